@@ -12,292 +12,6343 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{fmt_id, spawn_blocking, LedgerService};
+use crate::{fmt_id, spawn_blocking, LedgerService, SolutionVerifier};
 use snarkvm::{
     ledger::{
-        block::{Block, Transaction},
-        coinbase::{CoinbaseVerifyingKey, ProverSolution, PuzzleCommitment},
-        committee::Committee,
-        narwhal::{BatchCertificate, Data, Subdag, Transmission, TransmissionID},
+        authority::Authority,
+        block::{
+            block_reward, coinbase_reward, puzzle_reward, Block, ConfirmedTransaction, Input, Output, Ratify,
+            Transaction, Transition,
+        },
+        coinbase::{CoinbaseVerifyingKey, EpochChallenge, ProverSolution, PuzzleCommitment},
+        committee::{Committee, MIN_DELEGATOR_STAKE, MIN_VALIDATOR_STAKE},
+        narwhal::{BatchCertificate, BatchHeader, Data, Subdag, Transmission, TransmissionID},
         store::ConsensusStorage,
         Ledger,
     },
-    prelude::{bail, Field, Network, Result},
+    prelude::{
+        anyhow, bail, Address, Argument, Field, FromBytes, Future, Identifier, Literal, Network, Plaintext, ProgramID,
+        Register, Result, ToBits, ToBytes, Value, Zero,
+    },
+    synthesizer::{
+        process::{deployment_cost, execution_cost},
+        program::{CallOperator, Command, FinalizeOperation, Operand},
+    },
 };
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::{Mutex, RwLock};
 use std::{
-    fmt,
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    fmt, io,
     ops::Range,
+    path::Path,
+    str::FromStr,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
+    time::{Duration, Instant},
 };
+use tokio::sync::mpsc;
 
-/// A core ledger service.
-pub struct CoreLedgerService<N: Network, C: ConsensusStorage<N>> {
-    ledger: Ledger<N, C>,
-    coinbase_verifying_key: Arc<CoinbaseVerifyingKey<N>>,
-    shutdown: Arc<AtomicBool>,
-}
+/// The maximum number of blocks that may be scanned by a single range query.
+pub const MAX_BLOCK_RANGE: u32 = 1000; // blocks
+/// The maximum number of rounds that may be scanned by a single round range query.
+pub const MAX_ROUND_RANGE: u64 = 1000; // rounds
 
-impl<N: Network, C: ConsensusStorage<N>> CoreLedgerService<N, C> {
-    /// Initializes a new core ledger service.
-    pub fn new(ledger: Ledger<N, C>, shutdown: Arc<AtomicBool>) -> Self {
-        let coinbase_verifying_key = Arc::new(ledger.coinbase_puzzle().coinbase_verifying_key().clone());
-        Self { ledger, coinbase_verifying_key, shutdown }
+/// The default maximum size of a solution transmission.
+pub const DEFAULT_MAX_SOLUTION_BYTES: usize = 1024; // 1 KiB
+/// The default maximum size of a transaction transmission.
+pub const DEFAULT_MAX_TRANSACTION_BYTES: usize = 1024 * 1024; // 1 MiB
+
+/// The maximum number of rounds after its round that a certificate may be committed in (i.e. the BFT GC depth).
+const MAX_COMMIT_DELAY_IN_ROUNDS: u64 = 50; // rounds
+/// The maximum number of rounds, per committee member, that a block may advance past the latest block.
+const MAX_ROUND_ADVANCE_PER_MEMBER: u64 = 50; // rounds
+/// The interval at which checkpoint hashes are cached.
+const CHECKPOINT_HASH_INTERVAL: u32 = 1000; // blocks
+/// The rolling window over which verification throughput is measured.
+const THROUGHPUT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Returns the weighted median of the given `(value, weight)` pairs, ignoring zero-weight pairs.
+/// The weighted median is the smallest value at which the cumulative weight reaches half of the total weight.
+fn weighted_median(values: impl IntoIterator<Item = (i64, u64)>) -> Option<i64> {
+    // Sort the values with a non-zero weight.
+    let mut values = values.into_iter().filter(|(_, weight)| *weight > 0).collect::<Vec<_>>();
+    values.sort_unstable();
+    // Compute the total weight.
+    let total_weight = values.iter().map(|(_, weight)| *weight as u128).sum::<u128>();
+    // Find the value at which the cumulative weight reaches half of the total weight.
+    let mut cumulative_weight = 0u128;
+    for (value, weight) in values {
+        cumulative_weight += weight as u128;
+        if 2 * cumulative_weight >= total_weight {
+            return Some(value);
+        }
     }
+    None
 }
 
-impl<N: Network, C: ConsensusStorage<N>> fmt::Debug for CoreLedgerService<N, C> {
-    /// Implements a custom `fmt::Debug` for `CoreLedgerService`.
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("CoreLedgerService").field("current_committee", &self.current_committee()).finish()
+/// Returns the fraction of the committee's total stake held by the given (distinct) addresses.
+fn stake_fraction<N: Network>(committee: &Committee<N>, addresses: impl IntoIterator<Item = Address<N>>) -> f64 {
+    // Compute the stake held by the distinct addresses.
+    let stake = addresses
+        .into_iter()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .map(|address| committee.get_stake(address))
+        .sum::<u64>();
+    // Return the fraction of the total stake.
+    match committee.total_stake() {
+        0 => 0.0,
+        total_stake => stake as f64 / total_stake as f64,
     }
 }
 
-#[async_trait]
-impl<N: Network, C: ConsensusStorage<N>> LedgerService<N> for CoreLedgerService<N, C> {
-    /// Returns the latest round in the ledger.
-    fn latest_round(&self) -> u64 {
-        self.ledger.latest_round()
+/// Ensures the given genesis block parts are well-formed: the block is at height 0 and round 0, has a beacon
+/// authority, and has exactly one genesis ratification.
+fn ensure_genesis_parts<'a, N: Network>(
+    height: u32,
+    round: u64,
+    is_beacon: bool,
+    ratifications: impl IntoIterator<Item = &'a Ratify<N>>,
+) -> Result<()> {
+    // Ensure the height and round are zero.
+    if height != 0 || round != 0 {
+        bail!("Invalid genesis block - expected height 0 and round 0, found height {height} and round {round}");
     }
-
-    /// Returns the latest block height in the ledger.
-    fn latest_block_height(&self) -> u32 {
-        self.ledger.latest_height()
+    // Ensure the authority is a beacon authority.
+    if !is_beacon {
+        bail!("Invalid genesis block - expected a beacon authority");
     }
-
-    /// Returns the latest block in the ledger.
-    fn latest_block(&self) -> Block<N> {
-        self.ledger.latest_block()
+    // Ensure there is exactly one genesis ratification.
+    match ratifications.into_iter().collect::<Vec<_>>().as_slice() {
+        [Ratify::Genesis(..)] => Ok(()),
+        ratifications => {
+            bail!("Invalid genesis block - expected 1 genesis ratification, found {}", ratifications.len())
+        }
     }
+}
 
-    /// Returns `true` if the given block height exists in the ledger.
-    fn contains_block_height(&self, height: u32) -> bool {
-        self.ledger.contains_block_height(height).unwrap_or(false)
+/// Ensures the given program is not already deployed, according to `is_deployed`.
+fn ensure_program_not_deployed<N: Network>(
+    program_id: &ProgramID<N>,
+    is_deployed: impl Fn(&ProgramID<N>) -> bool,
+) -> Result<()> {
+    if is_deployed(program_id) {
+        bail!("Invalid deployment - program '{program_id}' already deployed");
     }
+    Ok(())
+}
 
-    /// Returns the block height for the given block hash, if it exists.
-    fn get_block_height(&self, hash: &N::BlockHash) -> Result<u32> {
-        self.ledger.get_height(hash)
+/// Ensures the function called by each of the given transitions is available, according to `is_available`.
+fn ensure_functions_available<'a, N: Network>(
+    transitions: impl IntoIterator<Item = &'a Transition<N>>,
+    is_available: impl Fn(&ProgramID<N>, &Identifier<N>) -> bool,
+) -> Result<()> {
+    for transition in transitions {
+        if !is_available(transition.program_id(), transition.function_name()) {
+            bail!(
+                "Function '{}/{}' in transition '{}' is not available",
+                transition.program_id(),
+                transition.function_name(),
+                transition.id()
+            );
+        }
     }
+    Ok(())
+}
 
-    /// Returns the block hash for the given block height, if it exists.
-    fn get_block_hash(&self, height: u32) -> Result<N::BlockHash> {
-        self.ledger.get_hash(height)
+/// Ensures the batch ID recomputed from the fields of the given batch header (its author, round, timestamp,
+/// transmission IDs, previous certificate IDs, and last election certificate IDs) matches the given certificate ID.
+fn ensure_certificate_id<N: Network>(certificate_id: Field<N>, batch_header: &BatchHeader<N>) -> Result<()> {
+    // Recompute the batch ID from the fields of the batch header.
+    let batch_id = BatchHeader::compute_batch_id(
+        batch_header.author(),
+        batch_header.round(),
+        batch_header.timestamp(),
+        batch_header.transmission_ids(),
+        batch_header.previous_certificate_ids(),
+        batch_header.last_election_certificate_ids(),
+    )?;
+    // Ensure the batch ID matches the certificate ID.
+    if batch_id != certificate_id {
+        bail!("Invalid certificate - expected ID {batch_id}, found {certificate_id}");
     }
+    Ok(())
+}
 
-    /// Returns the block for the given block height.
-    fn get_block(&self, height: u32) -> Result<Block<N>> {
-        self.ledger.get_block(height)
+/// Ensures the given previous certificates of the given certificate are from the round before it.
+fn ensure_certificate_round<'a, N: Network>(
+    certificate: &BatchCertificate<N>,
+    previous_certificates: impl IntoIterator<Item = &'a BatchCertificate<N>>,
+) -> Result<()> {
+    let round = certificate.round();
+    for previous_certificate in previous_certificates {
+        if previous_certificate.round().saturating_add(1) != round {
+            bail!(
+                "Invalid certificate - round {round} does not follow round {} of previous certificate {}",
+                previous_certificate.round(),
+                fmt_id(previous_certificate.id())
+            );
+        }
     }
+    Ok(())
+}
 
-    /// Returns the blocks in the given block range.
-    /// The range is inclusive of the start and exclusive of the end.
-    fn get_blocks(&self, heights: Range<u32>) -> Result<Vec<Block<N>>> {
-        self.ledger.get_blocks(heights)
+/// Returns the author of each of the given certificates, along with the offset (in seconds) of the certificate's
+/// timestamp from the given block timestamp.
+fn timestamp_skews<'a, N: Network>(
+    certificates: impl IntoIterator<Item = &'a BatchCertificate<N>>,
+    block_timestamp: i64,
+) -> Vec<(Address<N>, i64)> {
+    certificates
+        .into_iter()
+        .map(|certificate| (certificate.author(), certificate.timestamp().saturating_sub(block_timestamp)))
+        .collect()
+}
+
+/// Ensures the given target certificate ID is reachable from the given anchor certificate, by following
+/// previous certificate IDs through the given rounds of certificates.
+fn ensure_linked<N: Network>(
+    anchor: &BatchCertificate<N>,
+    rounds: &BTreeMap<u64, IndexSet<BatchCertificate<N>>>,
+    target_id: Field<N>,
+) -> Result<()> {
+    // Index the certificates by ID.
+    let certificates =
+        rounds.values().flatten().map(|certificate| (certificate.id(), certificate)).collect::<HashMap<_, _>>();
+    // Traverse the previous certificate IDs, starting from the anchor.
+    let mut visited = HashSet::new();
+    let mut buffer = vec![anchor];
+    while let Some(certificate) = buffer.pop() {
+        for previous_certificate_id in certificate.previous_certificate_ids() {
+            // Return early if the target is reached.
+            if *previous_certificate_id == target_id {
+                return Ok(());
+            }
+            // Continue the traversal through the given certificates.
+            if visited.insert(*previous_certificate_id) {
+                if let Some(previous_certificate) = certificates.get(previous_certificate_id) {
+                    buffer.push(previous_certificate);
+                }
+            }
+        }
     }
+    bail!("Anchor certificate '{}' is not linked to certificate '{}'", fmt_id(anchor.id()), fmt_id(target_id))
+}
 
-    /// Returns the solution for the given solution ID.
-    fn get_solution(&self, solution_id: &PuzzleCommitment<N>) -> Result<ProverSolution<N>> {
-        self.ledger.get_solution(solution_id)
+/// Returns the given finalize commands that are always executed, i.e. those before the first branch or `contains`
+/// guard, as the commands after it may be skipped depending on the state.
+fn unconditional_commands<N: Network>(commands: &[Command<N>]) -> impl Iterator<Item = &Command<N>> {
+    commands.iter().take_while(|command| {
+        !matches!(command, Command::BranchEq(..) | Command::BranchNeq(..) | Command::Contains(..))
+    })
+}
+
+/// Ensures each of the given `(program ID, mapping name, key)` mapping reads refers to an existing key,
+/// according to `contains_key`.
+fn ensure_mapping_keys_exist<N: Network>(
+    reads: &[(ProgramID<N>, Identifier<N>, Plaintext<N>)],
+    contains_key: impl Fn(&ProgramID<N>, &Identifier<N>, &Plaintext<N>) -> Result<bool>,
+) -> Result<()> {
+    for (program_id, mapping_name, key) in reads {
+        if !contains_key(program_id, mapping_name, key)? {
+            bail!("Key '{key}' does not exist in mapping '{program_id}/{mapping_name}'");
+        }
     }
+    Ok(())
+}
 
-    /// Returns the unconfirmed transaction for the given transaction ID.
-    fn get_unconfirmed_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>> {
-        self.ledger.get_unconfirmed_transaction(&transaction_id)
+/// Ensures the given puzzle commitments are unique, and that none of them were already committed,
+/// according to `contains`.
+fn ensure_unique_solutions<'a, N: Network>(
+    commitments: impl IntoIterator<Item = &'a PuzzleCommitment<N>>,
+    contains: impl Fn(&PuzzleCommitment<N>) -> Result<bool>,
+) -> Result<()> {
+    let mut seen = HashSet::new();
+    for commitment in commitments {
+        // Ensure the solution is not repeated within the block.
+        if !seen.insert(commitment) {
+            bail!("Solution '{commitment}' is repeated in the block");
+        }
+        // Ensure the solution was not already committed.
+        if contains(commitment)? {
+            bail!("Solution '{commitment}' was already committed");
+        }
     }
+    Ok(())
+}
 
-    /// Returns the batch certificate for the given batch certificate ID.
-    fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<BatchCertificate<N>> {
-        match self.ledger.get_batch_certificate(certificate_id) {
-            Ok(Some(certificate)) => Ok(certificate),
-            Ok(None) => bail!("No batch certificate found for certificate ID {certificate_id} in the ledger"),
-            Err(error) => Err(error),
+/// Ensures each of the given transmission IDs is known, according to `known`.
+/// Otherwise, returns an error with the first unknown transmission ID.
+fn ensure_transmissions_known<N: Network>(
+    transmission_ids: impl IntoIterator<Item = TransmissionID<N>>,
+    known: impl Fn(&TransmissionID<N>) -> Result<bool>,
+) -> Result<()> {
+    for transmission_id in transmission_ids {
+        if !known(&transmission_id)? {
+            bail!("Transmission '{transmission_id}' was not previously seen");
         }
     }
+    Ok(())
+}
 
-    /// Returns the current committee.
-    fn current_committee(&self) -> Result<Committee<N>> {
-        self.ledger.latest_committee()
+/// Ensures the given total bonded amount of the caller meets the minimum stake, which is `MIN_VALIDATOR_STAKE`
+/// if the caller bonds to itself, and `MIN_DELEGATOR_STAKE` if the caller delegates to another validator.
+fn ensure_bond_minimum<N: Network>(caller: &Address<N>, validator: &Address<N>, total: u64) -> Result<()> {
+    let minimum = match caller == validator {
+        true => MIN_VALIDATOR_STAKE,
+        false => MIN_DELEGATOR_STAKE,
+    };
+    if total < minimum {
+        bail!(
+            "Bonded amount of {total} microcredits from '{caller}' to '{validator}' is below the minimum of {minimum}"
+        );
     }
+    Ok(())
+}
 
-    /// Returns the committee for the given round.
-    /// If the given round is in the future, then the current committee is returned.
-    fn get_committee_for_round(&self, round: u64) -> Result<Committee<N>> {
-        match self.ledger.get_committee_for_round(round)? {
-            // Return the committee if it exists.
-            Some(committee) => Ok(committee),
-            // Return the current committee if the round is in the future.
-            None => {
-                // Retrieve the current committee.
-                let current_committee = self.current_committee()?;
-                // Return the current committee if the round is in the future.
-                match current_committee.starting_round() <= round {
-                    true => Ok(current_committee),
-                    false => bail!("No committee found for round {round} in the ledger"),
-                }
+/// Returns the mapping ID of the given mapping, as derived by the finalize store.
+fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Field<N>> {
+    let mut preimage = Vec::new();
+    program_id.write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator
+    mapping_name.write_bits_le(&mut preimage);
+    N::hash_bhp1024(&preimage)
+}
+
+/// Returns the key ID of the given key in the given mapping, as derived by the finalize store.
+fn to_key_id<N: Network>(
+    program_id: &ProgramID<N>,
+    mapping_name: &Identifier<N>,
+    key: &Plaintext<N>,
+) -> Result<Field<N>> {
+    let mut preimage = Vec::new();
+    program_id.write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator
+    mapping_name.write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator
+    key.write_bits_le(&mut preimage);
+    N::hash_bhp1024(&preimage)
+}
+
+/// Resolves the keys set by the given finalize operations against the entries of the given mappings,
+/// returning each key along with its value in the given mappings.
+///
+/// Finalize operations only commit to the hashes of keys, so removed keys and keys absent from the given mappings
+/// cannot be resolved, and are skipped.
+#[allow(clippy::type_complexity)]
+fn resolve_mapping_updates<'a, N: Network>(
+    operations: impl IntoIterator<Item = &'a FinalizeOperation<N>>,
+    mappings: &[(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)],
+) -> Result<Vec<(ProgramID<N>, Identifier<N>, Plaintext<N>, Value<N>)>> {
+    // Index the mappings by mapping ID.
+    let mut mapping_ids = HashMap::with_capacity(mappings.len());
+    for (program_id, mapping_name, entries) in mappings {
+        mapping_ids.insert(to_mapping_id(program_id, mapping_name)?, (program_id, mapping_name, entries));
+    }
+    // Resolve the keys that were set.
+    let mut updates = Vec::new();
+    for operation in operations {
+        let (mapping_id, key_id) = match operation {
+            FinalizeOperation::InsertKeyValue(mapping_id, key_id, _) => (mapping_id, key_id),
+            FinalizeOperation::UpdateKeyValue(mapping_id, _, key_id, _) => (mapping_id, key_id),
+            _ => continue,
+        };
+        let Some((program_id, mapping_name, entries)) = mapping_ids.get(mapping_id) else {
+            continue;
+        };
+        for (key, value) in entries.iter() {
+            if to_key_id(program_id, mapping_name, key)? == *key_id {
+                updates.push((**program_id, **mapping_name, key.clone(), value.clone()));
+                break;
             }
         }
     }
+    Ok(updates)
+}
 
-    /// Returns the previous committee for the given round.
-    /// If the previous round is in the future, then the current committee is returned.
-    fn get_previous_committee_for_round(&self, round: u64) -> Result<Committee<N>> {
-        // Get the round number for the previous committee. Note, we subtract 2 from odd rounds,
-        // because committees are updated in even rounds.
-        let previous_round = match round % 2 == 0 {
-            true => round.saturating_sub(1),
-            false => round.saturating_sub(2),
-        };
-
-        // Retrieve the committee for the previous round.
-        self.get_committee_for_round(previous_round)
+/// Ensures every certificate in the given rounds is within the GC depth of the given anchor round,
+/// i.e. that it may still be committed by the anchor.
+fn ensure_rounds_within_gc<N: Network>(
+    rounds: &BTreeMap<u64, IndexSet<BatchCertificate<N>>>,
+    anchor_round: u64,
+) -> Result<()> {
+    let gc_round = anchor_round.saturating_sub(MAX_COMMIT_DELAY_IN_ROUNDS);
+    if let Some(certificate) = rounds.values().flatten().find(|certificate| certificate.round() < gc_round) {
+        bail!("Subdag contains a round {} certificate, below the GC round {gc_round}", certificate.round());
     }
+    Ok(())
+}
 
-    /// Returns `true` if the ledger contains the given certificate ID in block history.
-    fn contains_certificate(&self, certificate_id: &Field<N>) -> Result<bool> {
-        self.ledger.contains_certificate(certificate_id)
+/// Ensures the prover of each of the given solutions is in the given registry.
+/// If the registry is empty, then any prover is authorized.
+fn ensure_authorized_provers<'a, N: Network>(
+    solutions: impl IntoIterator<Item = &'a ProverSolution<N>>,
+    registry: &HashSet<Address<N>>,
+) -> Result<()> {
+    // If the registry is empty, then the network is permissionless.
+    if registry.is_empty() {
+        return Ok(());
     }
+    // Ensure each prover is in the registry.
+    for solution in solutions {
+        if !registry.contains(&solution.address()) {
+            bail!("Solution '{}' is from unauthorized prover '{}'", solution.commitment(), solution.address());
+        }
+    }
+    Ok(())
+}
 
-    /// Returns `true` if the transmission exists in the ledger.
-    fn contains_transmission(&self, transmission_id: &TransmissionID<N>) -> Result<bool> {
-        match transmission_id {
-            TransmissionID::Ratification => Ok(false),
-            TransmissionID::Solution(puzzle_commitment) => self.ledger.contains_puzzle_commitment(puzzle_commitment),
-            TransmissionID::Transaction(transaction_id) => self.ledger.contains_transaction_id(transaction_id),
+/// Returns the round at which the members of the given current committee took effect, given the committees of
+/// the preceding blocks, from newest to oldest. This is the starting round of the oldest committee in the latest
+/// run of committees with the same members and stakes as the current committee.
+fn committee_activation_round<N: Network>(
+    current: &Committee<N>,
+    history: impl IntoIterator<Item = Result<Committee<N>>>,
+) -> Result<u64> {
+    let mut activation_round = current.starting_round();
+    for committee in history {
+        let committee = committee?;
+        // Stop at the last change to the committee.
+        if committee.members() != current.members() {
+            break;
         }
+        activation_round = activation_round.min(committee.starting_round());
     }
+    Ok(activation_round)
+}
 
-    /// Ensures the given transmission ID matches the given transmission.
-    fn ensure_transmission_id_matches(
-        &self,
-        transmission_id: TransmissionID<N>,
-        transmission: &mut Transmission<N>,
-    ) -> Result<()> {
-        match (transmission_id, transmission) {
-            (TransmissionID::Ratification, Transmission::Ratification) => {}
-            (TransmissionID::Transaction(expected_transaction_id), Transmission::Transaction(transaction_data)) => {
-                match transaction_data.clone().deserialize_blocking() {
-                    Ok(transaction) => {
-                        if transaction.id() != expected_transaction_id {
-                            bail!(
-                                "Received mismatching transaction ID  - expected {}, found {}",
-                                fmt_id(expected_transaction_id),
-                                fmt_id(transaction.id()),
-                            );
-                        }
+/// Returns the ID of each of the given transactions that was rejected during finalize, along with the reason.
+///
+/// The ledger does not record why a transaction was rejected, so the reason only describes what was rejected.
+fn rejected_transactions<'a, N: Network>(
+    transactions: impl IntoIterator<Item = &'a ConfirmedTransaction<N>>,
+) -> Result<Vec<(N::TransactionID, String)>> {
+    let mut rejected = Vec::new();
+    for transaction in transactions {
+        let reason = match transaction {
+            ConfirmedTransaction::RejectedDeploy(..) => "The deployment was rejected during finalize",
+            ConfirmedTransaction::RejectedExecute(..) => "The execution was rejected during finalize",
+            _ => continue,
+        };
+        rejected.push((transaction.to_unconfirmed_transaction_id()?, reason.to_string()));
+    }
+    Ok(rejected)
+}
 
-                        // Update the transmission with the deserialized transaction.
-                        *transaction_data = Data::Object(transaction);
-                    }
-                    Err(err) => {
-                        bail!("Failed to deserialize transaction: {err}");
-                    }
-                }
+/// Returns the amounts (in microcredits) that the given transactions bonded to the given validator, and that the
+/// validator itself unbonded, from the finalize arguments of their accepted `bond_public` and `unbond_public` calls.
+fn validator_stake_operations<'a, N: Network>(
+    transactions: impl IntoIterator<Item = &'a ConfirmedTransaction<N>>,
+    validator: &Address<N>,
+) -> Result<(u64, u64)> {
+    let credits = ProgramID::from_str("credits.aleo")?;
+    let (bond_public, unbond_public) = (Identifier::from_str("bond_public")?, Identifier::from_str("unbond_public")?);
+    let (mut bonded, mut unbonded) = (0u64, 0u64);
+    for transaction in transactions {
+        let ConfirmedTransaction::AcceptedExecute(..) = transaction else {
+            continue;
+        };
+        for transition in transaction.transaction().transitions() {
+            // Retrieve the finalize arguments of the `credits.aleo` transition.
+            let Some(Output::Future(_, Some(future))) = transition.outputs().last() else {
+                continue;
+            };
+            if *transition.program_id() != credits {
+                continue;
             }
-            (TransmissionID::Solution(expected_commitment), Transmission::Solution(solution_data)) => {
-                match solution_data.clone().deserialize_blocking() {
-                    Ok(solution) => {
-                        if solution.commitment() != expected_commitment {
-                            bail!(
-                                "Received mismatching solution ID - expected {}, found {}",
-                                fmt_id(expected_commitment),
-                                fmt_id(solution.commitment()),
-                            );
-                        }
-
-                        // Update the transmission with the deserialized solution.
-                        *solution_data = Data::Object(solution);
+            let arguments = future
+                .arguments()
+                .iter()
+                .map(|argument| match argument {
+                    Argument::Plaintext(plaintext) => Ok(Value::Plaintext(plaintext.clone())),
+                    Argument::Future(..) => bail!("Expected a plaintext staking argument, found '{argument}'"),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            // Accumulate the bonds to the validator, and the unbonds of the validator.
+            match arguments.as_slice() {
+                [_, to, amount] if *transition.function_name() == bond_public => {
+                    if value_to_address(to)? == *validator {
+                        bonded = bonded.saturating_add(value_to_u64(amount)?);
                     }
-                    Err(err) => {
-                        bail!("Failed to deserialize solution: {err}");
+                }
+                [caller, amount] if *transition.function_name() == unbond_public => {
+                    if value_to_address(caller)? == *validator {
+                        unbonded = unbonded.saturating_add(value_to_u64(amount)?);
                     }
                 }
-            }
-            _ => {
-                bail!("Mismatching `(transmission_id, transmission)` pair");
+                _ => (),
             }
         }
-
-        Ok(())
     }
+    Ok((bonded, unbonded))
+}
 
-    /// Checks the given solution is well-formed.
-    async fn check_solution_basic(
-        &self,
-        puzzle_commitment: PuzzleCommitment<N>,
-        solution: Data<ProverSolution<N>>,
-    ) -> Result<()> {
-        // Deserialize the solution.
-        let solution = spawn_blocking!(solution.deserialize_blocking())?;
-        // Ensure the puzzle commitment matches in the solution.
-        if puzzle_commitment != solution.commitment() {
-            bail!("Invalid solution - expected {puzzle_commitment}, found {}", solution.commitment());
+/// Returns the number of stakers that the given finalize operations inserted into the `credits.aleo/bonded`
+/// mapping, less the number of stakers they removed from it.
+fn staker_count_delta<'a, N: Network>(operations: impl IntoIterator<Item = &'a FinalizeOperation<N>>) -> Result<i64> {
+    let bonded_id = to_mapping_id(&ProgramID::from_str("credits.aleo")?, &Identifier::from_str("bonded")?)?;
+    let mut delta = 0i64;
+    for operation in operations {
+        match operation {
+            FinalizeOperation::InsertKeyValue(mapping_id, ..) if *mapping_id == bonded_id => delta += 1,
+            FinalizeOperation::RemoveKeyValue(mapping_id, ..) if *mapping_id == bonded_id => delta -= 1,
+            _ => (),
         }
+    }
+    Ok(delta)
+}
 
-        // Retrieve the coinbase verifying key.
-        let coinbase_verifying_key = self.coinbase_verifying_key.clone();
-        // Compute the current epoch challenge.
-        let epoch_challenge = self.ledger.latest_epoch_challenge()?;
-        // Retrieve the current proof target.
-        let proof_target = self.ledger.latest_proof_target();
+/// Ensures the given number of solutions does not exceed the given maximum number of solutions per block.
+fn ensure_solution_count(num_solutions: usize, max_solutions: usize) -> Result<()> {
+    if num_solutions > max_solutions {
+        bail!("Block contains {num_solutions} solutions, exceeding the maximum of {max_solutions}");
+    }
+    Ok(())
+}
 
-        // Ensure that the prover solution is valid for the given epoch.
-        if !spawn_blocking!(solution.verify(&coinbase_verifying_key, &epoch_challenge, proof_target))? {
-            bail!("Invalid prover solution '{puzzle_commitment}' for the current epoch.");
+/// Returns the transmission IDs in the given certificates that were authored by the given address.
+fn author_transmission_ids<N: Network>(
+    certificates: &[BatchCertificate<N>],
+    author: &Address<N>,
+) -> Vec<TransmissionID<N>> {
+    certificates
+        .iter()
+        .filter(|certificate| certificate.author() == *author)
+        .flat_map(|certificate| certificate.transmission_ids().iter().copied())
+        .collect()
+}
+
+/// Returns the smallest number of the given stakes whose sum reaches the given threshold,
+/// or `None` if all of the stakes combined do not reach it.
+fn min_stakes_for_threshold(stakes: impl IntoIterator<Item = u64>, threshold: u64) -> Option<usize> {
+    // Sort the stakes from highest to lowest.
+    let mut stakes = stakes.into_iter().collect::<Vec<_>>();
+    stakes.sort_unstable_by(|a, b| b.cmp(a));
+    // Accumulate the highest stakes until the threshold is reached.
+    let mut total = 0u64;
+    for (count, stake) in (1..).zip(stakes) {
+        total = total.saturating_add(stake);
+        if total >= threshold {
+            return Some(count);
         }
-        Ok(())
     }
+    None
+}
 
-    /// Checks the given transaction is well-formed and unique.
-    async fn check_transaction_basic(
-        &self,
-        transaction_id: N::TransactionID,
-        transaction: Data<Transaction<N>>,
-    ) -> Result<()> {
-        // Deserialize the transaction.
-        let transaction = spawn_blocking!(transaction.deserialize_blocking())?;
-        // Ensure the transaction ID matches in the transaction.
-        if transaction_id != transaction.id() {
-            bail!("Invalid transaction - expected {transaction_id}, found {}", transaction.id());
+/// Returns the smallest number of the committee's highest-staked members that together reach the quorum threshold.
+fn min_members_for_quorum<N: Network>(committee: &Committee<N>) -> Result<usize> {
+    let stakes = committee.members().values().map(|(stake, _)| *stake);
+    min_stakes_for_threshold(stakes, committee.quorum_threshold())
+        .ok_or_else(|| anyhow!("The committee cannot reach the quorum threshold"))
+}
+
+/// Returns the fraction of the committee's total stake held by the smallest set of its highest-staked members
+/// that together reach the quorum threshold.
+fn quorum_concentration_of<N: Network>(committee: &Committee<N>) -> Result<f64> {
+    // Compute the smallest number of members that reach the quorum threshold.
+    let count = min_members_for_quorum(committee)?;
+    // Sort the stakes from highest to lowest.
+    let mut stakes = committee.members().values().map(|(stake, _)| *stake).collect::<Vec<_>>();
+    stakes.sort_unstable_by(|a, b| b.cmp(a));
+    // Return the fraction of the total stake held by those members.
+    let stake = stakes[..count].iter().sum::<u64>();
+    Ok(stake as f64 / committee.total_stake() as f64)
+}
+
+/// Returns the members of the committee that are not among the given authors, in committee order.
+fn absent_members<N: Network>(
+    committee: &Committee<N>,
+    authors: impl IntoIterator<Item = Address<N>>,
+) -> Vec<Address<N>> {
+    let authors = authors.into_iter().collect::<HashSet<_>>();
+    committee.members().keys().filter(|address| !authors.contains(address)).copied().collect()
+}
+
+/// Returns the weighted average offset of the given `(timestamp, weight)` pairs from the earliest timestamp,
+/// or `None` if the total weight is zero.
+fn weighted_average_offset(values: impl IntoIterator<Item = (i64, u64)>) -> Option<f64> {
+    let values = values.into_iter().collect::<Vec<_>>();
+    // Retrieve the earliest timestamp.
+    let earliest = values.iter().map(|(timestamp, _)| *timestamp).min()?;
+    // Compute the total weight.
+    let total_weight = values.iter().map(|(_, weight)| *weight as f64).sum::<f64>();
+    if total_weight == 0.0 {
+        return None;
+    }
+    // Compute the weighted average offset.
+    let weighted_sum = values
+        .iter()
+        .map(|(timestamp, weight)| timestamp.saturating_sub(earliest) as f64 * *weight as f64)
+        .sum::<f64>();
+    Some(weighted_sum / total_weight)
+}
+
+/// Returns the fraction of the committee's total stake held by its highest-staked member.
+fn largest_share_of<N: Network>(committee: &Committee<N>) -> f64 {
+    let largest = committee.members().iter().max_by_key(|(_, (stake, _))| *stake).map(|(address, _)| *address);
+    stake_fraction(committee, largest)
+}
+
+/// Ensures no serial number is spent by more than one of the given transactions.
+fn ensure_unique_serial_numbers<'a, N: Network>(
+    transactions: impl IntoIterator<Item = &'a Transaction<N>>,
+) -> Result<()> {
+    // Track the transaction that spends each serial number.
+    let mut spenders = HashMap::new();
+    for transaction in transactions {
+        for serial_number in transaction.serial_numbers() {
+            // Ensure the serial number was not already spent by another transaction.
+            if let Some(spender) = spenders.insert(*serial_number, transaction.id()) {
+                bail!(
+                    "Serial number '{}' is spent by both transaction '{}' and '{}'",
+                    fmt_id(serial_number),
+                    fmt_id(spender),
+                    fmt_id(transaction.id())
+                );
+            }
         }
-        // Check if the transmission is a fee transaction.
-        if transaction.is_fee() {
-            bail!("Invalid transaction - 'Transaction::fee' type is not valid at this stage ({})", transaction.id());
+    }
+    Ok(())
+}
+
+/// Ensures none of the serial numbers spent by the given transactions were already spent, according to `is_spent`.
+fn ensure_unspent_serial_numbers<'a, N: Network>(
+    transactions: impl IntoIterator<Item = &'a Transaction<N>>,
+    is_spent: impl Fn(&Field<N>) -> Result<bool>,
+) -> Result<()> {
+    for transaction in transactions {
+        for serial_number in transaction.serial_numbers() {
+            // Ensure the serial number was not spent in an earlier block.
+            if is_spent(serial_number)? {
+                bail!(
+                    "Serial number '{}' in transaction '{}' was already spent",
+                    fmt_id(serial_number),
+                    fmt_id(transaction.id())
+                );
+            }
         }
-        // Check the transaction is well-formed.
-        let ledger = self.ledger.clone();
-        spawn_blocking!(ledger.check_transaction_basic(&transaction, None, &mut rand::thread_rng()))
     }
+    Ok(())
+}
 
-    /// Checks the given block is valid next block.
-    fn check_next_block(&self, block: &Block<N>) -> Result<()> {
-        self.ledger.check_next_block(block, &mut rand::thread_rng())
+/// Ensures the given number of transitions does not exceed the given maximum number of transitions per transaction.
+fn ensure_transition_count(num_transitions: usize, max_transitions: usize) -> Result<()> {
+    if num_transitions > max_transitions {
+        bail!("Transaction contains {num_transitions} transitions, exceeding the maximum of {max_transitions}");
     }
+    Ok(())
+}
 
-    /// Returns a candidate for the next block in the ledger, using a committed subdag and its transmissions.
-    #[cfg(feature = "ledger-write")]
-    fn prepare_advance_to_next_quorum_block(
-        &self,
-        subdag: Subdag<N>,
-        transmissions: IndexMap<TransmissionID<N>, Transmission<N>>,
-    ) -> Result<Block<N>> {
-        self.ledger.prepare_advance_to_next_quorum_block(subdag, transmissions)
+/// Ensures none of the given transaction IDs is repeated.
+fn ensure_unique_transaction_ids<'a, N: Network>(
+    transaction_ids: impl IntoIterator<Item = &'a N::TransactionID>,
+) -> Result<()> {
+    let mut seen = HashSet::new();
+    for transaction_id in transaction_ids {
+        if !seen.insert(transaction_id) {
+            bail!("Found a duplicate transaction '{transaction_id}'");
+        }
     }
+    Ok(())
+}
 
-    /// Adds the given block as the next block in the ledger.
-    #[cfg(feature = "ledger-write")]
-    fn advance_to_next_block(&self, block: &Block<N>) -> Result<()> {
-        // If the Ctrl-C handler registered the signal, then skip advancing to the next block.
+/// Returns the size in bytes of the given data, if it is still serialized.
+fn buffer_size<T>(data: &Data<T>) -> Option<usize> {
+    match data {
+        Data::Object(..) => None,
+        Data::Buffer(bytes) => Some(bytes.len()),
+    }
+}
+
+/// Returns the `u64` amount in the given value.
+fn value_to_u64<N: Network>(value: &Value<N>) -> Result<u64> {
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::U64(amount), _)) => Ok(**amount),
+        _ => bail!("Expected a 'u64' value, found '{value}'"),
+    }
+}
+
+/// Returns the commission in the given value, if it is a `u8` value.
+fn value_to_commission<N: Network>(value: &Value<N>) -> Result<u8> {
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::U8(commission), _)) => Ok(**commission),
+        _ => bail!("Expected a 'u8' commission, found '{value}'"),
+    }
+}
+
+/// Returns the address in the given value.
+fn value_to_address<N: Network>(value: &Value<N>) -> Result<Address<N>> {
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::Address(address), _)) => Ok(*address),
+        _ => bail!("Expected an 'address' value, found '{value}'"),
+    }
+}
+
+/// Returns the `u64` amount in the given member of the given struct value.
+fn struct_member_to_u64<N: Network>(value: &Value<N>, member: &str) -> Result<u64> {
+    match value {
+        Value::Plaintext(Plaintext::Struct(members, _)) => match members.get(&Identifier::from_str(member)?) {
+            Some(Plaintext::Literal(Literal::U64(amount), _)) => Ok(**amount),
+            _ => bail!("Expected a 'u64' member '{member}' in '{value}'"),
+        },
+        _ => bail!("Expected a struct value, found '{value}'"),
+    }
+}
+
+/// Returns the given member of the given struct value, or `None` if the value is not a struct or lacks the member.
+fn struct_member<N: Network>(value: &Value<N>, member: &Identifier<N>) -> Option<Value<N>> {
+    match value {
+        Value::Plaintext(Plaintext::Struct(members, _)) => members.get(member).cloned().map(Value::Plaintext),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the given plaintext is, or contains, the given address.
+fn plaintext_references<N: Network>(plaintext: &Plaintext<N>, address: &Address<N>) -> bool {
+    match plaintext {
+        Plaintext::Literal(Literal::Address(candidate), _) => candidate == address,
+        Plaintext::Struct(members, _) => members.values().any(|member| plaintext_references(member, address)),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the arguments of the given future, or of its nested futures, contain the given address.
+fn future_references<N: Network>(future: &Future<N>, address: &Address<N>) -> bool {
+    future.arguments().iter().any(|argument| match argument {
+        Argument::Plaintext(plaintext) => plaintext_references(plaintext, address),
+        Argument::Future(future) => future_references(future, address),
+    })
+}
+
+/// Returns `true` if the public inputs or outputs of the given transition contain the given address.
+fn transition_references<N: Network>(transition: &Transition<N>, address: &Address<N>) -> bool {
+    transition.inputs().iter().any(|input| match input {
+        Input::Public(_, Some(plaintext)) => plaintext_references(plaintext, address),
+        _ => false,
+    }) || transition.outputs().iter().any(|output| match output {
+        Output::Public(_, Some(plaintext)) => plaintext_references(plaintext, address),
+        Output::Future(_, Some(future)) => future_references(future, address),
+        _ => false,
+    })
+}
+
+/// Returns `true` if the given ratification allocates a balance or stake to the given address.
+fn ratification_references<N: Network>(ratification: &Ratify<N>, address: &Address<N>) -> bool {
+    match ratification {
+        Ratify::Genesis(committee, public_balances) => {
+            committee.is_committee_member(*address) || public_balances.contains_key(address)
+        }
+        Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
+    }
+}
+
+/// Returns the first of the given heights for which `seen` holds, or `None` if it holds for none of them.
+fn first_height_where(heights: Range<u32>, seen: impl Fn(u32) -> Result<bool>) -> Result<Option<u32>> {
+    for height in heights {
+        if seen(height)? {
+            return Ok(Some(height));
+        }
+    }
+    Ok(None)
+}
+
+/// The committed certificates of a round, along with their edges to the certificates of the previous round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoundDag<N: Network> {
+    /// The round.
+    pub round: u64,
+    /// The `(certificate ID, author, previous certificate IDs)` of each certificate in the round.
+    pub certificates: Vec<(Field<N>, Address<N>, Vec<Field<N>>)>,
+}
+
+impl<N: Network> RoundDag<N> {
+    /// Initializes the round DAG from the given certificates, ignoring certificates from other rounds.
+    pub fn new(round: u64, certificates: &[BatchCertificate<N>]) -> Self {
+        let certificates = certificates
+            .iter()
+            .filter(|certificate| certificate.round() == round)
+            .map(|certificate| {
+                (
+                    certificate.id(),
+                    certificate.author(),
+                    certificate.previous_certificate_ids().iter().copied().collect(),
+                )
+            })
+            .collect();
+        Self { round, certificates }
+    }
+}
+
+/// The participation of a validator over an epoch.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParticipationStats {
+    /// The number of rounds in which the validator authored a committed certificate.
+    pub rounds_authored: u64,
+    /// The number of blocks in which the validator authored the leader certificate.
+    pub blocks_led: u64,
+    /// The rewards (in microcredits) earned by the validator.
+    pub rewards_earned: u64,
+}
+
+/// The number of transmissions verified per second, over the rolling throughput window.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ThroughputStats {
+    /// The number of transactions verified per second.
+    pub tx_per_sec: f64,
+    /// The number of solutions verified per second.
+    pub solution_per_sec: f64,
+}
+
+/// The kind of a bonding event.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BondingEventKind {
+    /// A `credits.aleo/bond_public` call.
+    Bond,
+    /// A `credits.aleo/unbond_public` call.
+    Unbond,
+    /// A `credits.aleo/claim_unbond_public` call.
+    ClaimUnbond,
+}
+
+/// A bonding event involving an address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BondingEvent<N: Network> {
+    /// The height of the block containing the event.
+    pub height: u32,
+    /// The ID of the transaction containing the event.
+    pub transaction_id: N::TransactionID,
+    /// The kind of the event.
+    pub kind: BondingEventKind,
+    /// The amount (in microcredits) bonded or unbonded, if the event specifies one.
+    pub amount: Option<u64>,
+}
+
+/// Returns the kind of bonding event of the given finalize future, along with its amount,
+/// or `None` if the future is not from a `credits.aleo` bonding function.
+fn bonding_event_of<N: Network>(future: &Future<N>) -> Option<(BondingEventKind, Option<u64>)> {
+    // Ensure the future is from `credits.aleo`.
+    if future.program_id().to_string() != "credits.aleo" {
+        return None;
+    }
+    // Retrieve the kind of the event.
+    let kind = match future.function_name().to_string().as_str() {
+        "bond_public" => BondingEventKind::Bond,
+        "unbond_public" => BondingEventKind::Unbond,
+        "claim_unbond_public" => BondingEventKind::ClaimUnbond,
+        _ => return None,
+    };
+    // Retrieve the amount, which is the last argument of a bond or unbond.
+    let amount = match (kind, future.arguments().last()) {
+        (
+            BondingEventKind::Bond | BondingEventKind::Unbond,
+            Some(Argument::Plaintext(Plaintext::Literal(Literal::U64(amount), _))),
+        ) => Some(**amount),
+        _ => None,
+    };
+    Some((kind, amount))
+}
+
+/// Returns `true` if the given finalize future is from a `credits.aleo` function that changes the committee.
+fn is_staking_future<N: Network>(future: &Future<N>) -> bool {
+    future.program_id().to_string() == "credits.aleo"
+        && matches!(
+            future.function_name().to_string().as_str(),
+            "bond_public" | "unbond_public" | "unbond_delegator_as_validator" | "set_validator_state"
+        )
+}
+
+/// Returns the given candidate addresses whose `credits.aleo/committee` entries were inserted or updated
+/// by the given finalize operations.
+fn updated_committee_members<'a, N: Network>(
+    operations: impl IntoIterator<Item = &'a FinalizeOperation<N>>,
+    candidates: &HashSet<Address<N>>,
+) -> Result<HashSet<Address<N>>> {
+    let (credits, committee) = (ProgramID::from_str("credits.aleo")?, Identifier::from_str("committee")?);
+    let committee_id = to_mapping_id(&credits, &committee)?;
+    // Index the candidates by the key ID of their committee entry.
+    let mut key_ids = HashMap::with_capacity(candidates.len());
+    for address in candidates {
+        key_ids.insert(to_key_id(&credits, &committee, &Plaintext::from(Literal::Address(*address)))?, *address);
+    }
+    // Collect the candidates whose committee entries were set.
+    let mut updated = HashSet::new();
+    for operation in operations {
+        let (mapping_id, key_id) = match operation {
+            FinalizeOperation::InsertKeyValue(mapping_id, key_id, _) => (mapping_id, key_id),
+            FinalizeOperation::UpdateKeyValue(mapping_id, _, key_id, _) => (mapping_id, key_id),
+            _ => continue,
+        };
+        if *mapping_id == committee_id {
+            if let Some(address) = key_ids.get(key_id) {
+                updated.insert(*address);
+            }
+        }
+    }
+    Ok(updated)
+}
+
+/// Ensures each change from the `from` committee to the `to` committee is explained by a staking transaction
+/// involving one of the given addresses. A member joining or leaving, a decrease in stake, or a change in whether
+/// the member is open to delegators must be explained. Stake increases are not checked, as they may be rewards.
+fn ensure_committee_changes_explained<N: Network>(
+    from: &Committee<N>,
+    to: &Committee<N>,
+    explained: &HashSet<Address<N>>,
+) -> Result<()> {
+    for address in from.members().keys().chain(to.members().keys()) {
+        let is_changed = match (from.members().get(address), to.members().get(address)) {
+            (Some((from_stake, from_is_open)), Some((to_stake, to_is_open))) => {
+                to_stake < from_stake || from_is_open != to_is_open
+            }
+            _ => true,
+        };
+        if is_changed && !explained.contains(address) {
+            bail!("The committee change for '{address}' is not explained by a staking transaction");
+        }
+    }
+    Ok(())
+}
+
+/// Records a verification at the given instant, and drops the verifications outside the throughput window.
+fn record_verification(verifications: &Mutex<VecDeque<Instant>>, now: Instant) {
+    let mut verifications = verifications.lock();
+    verifications.push_back(now);
+    prune_verifications(&mut verifications, now);
+}
+
+/// Returns the number of verifications per second within the throughput window ending at the given instant.
+fn verifications_per_sec(verifications: &Mutex<VecDeque<Instant>>, now: Instant) -> f64 {
+    let mut verifications = verifications.lock();
+    prune_verifications(&mut verifications, now);
+    verifications.len() as f64 / THROUGHPUT_WINDOW.as_secs_f64()
+}
+
+/// Drops the verifications that precede the throughput window ending at the given instant.
+fn prune_verifications(verifications: &mut VecDeque<Instant>, now: Instant) {
+    while verifications.front().is_some_and(|instant| now.saturating_duration_since(*instant) > THROUGHPUT_WINDOW) {
+        verifications.pop_front();
+    }
+}
+
+/// The confirmed entries of every mapping in the finalize store, as of a block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeSnapshot<N: Network> {
+    /// The height of the block.
+    pub height: u32,
+    /// The hash of the block.
+    pub block_hash: N::BlockHash,
+    /// The `(program ID, mapping name, entries)` of each mapping.
+    pub mappings: Vec<(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>,
+}
+
+impl<N: Network> ToBytes for FinalizeSnapshot<N> {
+    fn write_le<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        self.height.write_le(&mut writer)?;
+        self.block_hash.write_le(&mut writer)?;
+        u32::try_from(self.mappings.len()).map_err(io::Error::other)?.write_le(&mut writer)?;
+        for (program_id, mapping_name, entries) in &self.mappings {
+            program_id.write_le(&mut writer)?;
+            mapping_name.write_le(&mut writer)?;
+            u32::try_from(entries.len()).map_err(io::Error::other)?.write_le(&mut writer)?;
+            for (key, value) in entries {
+                key.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for FinalizeSnapshot<N> {
+    fn read_le<R: io::Read>(mut reader: R) -> io::Result<Self> {
+        let height = u32::read_le(&mut reader)?;
+        let block_hash = N::BlockHash::read_le(&mut reader)?;
+        let num_mappings = u32::read_le(&mut reader)?;
+        let mut mappings = Vec::new();
+        for _ in 0..num_mappings {
+            let program_id = ProgramID::read_le(&mut reader)?;
+            let mapping_name = Identifier::read_le(&mut reader)?;
+            let num_entries = u32::read_le(&mut reader)?;
+            let mut entries = Vec::new();
+            for _ in 0..num_entries {
+                entries.push((Plaintext::read_le(&mut reader)?, Value::read_le(&mut reader)?));
+            }
+            mappings.push((program_id, mapping_name, entries));
+        }
+        Ok(Self { height, block_hash, mappings })
+    }
+}
+
+/// Ensures the given rounds of certificates are non-empty and contiguous, and that each certificate is in its round.
+fn ensure_contiguous_rounds<N: Network>(rounds: &BTreeMap<u64, IndexSet<BatchCertificate<N>>>) -> Result<()> {
+    let mut previous_round = None;
+    for (round, certificates) in rounds {
+        // Ensure the round is contiguous with the previous round.
+        if let Some(previous_round) = previous_round {
+            if *round != previous_round + 1 {
+                bail!("Subdag skips from round {previous_round} to round {round}");
+            }
+        }
+        // Ensure the round is not empty.
+        if certificates.is_empty() {
+            bail!("Subdag contains no certificates for round {round}");
+        }
+        // Ensure each certificate is in its round.
+        if let Some(certificate) = certificates.iter().find(|certificate| certificate.round() != *round) {
+            bail!("Subdag contains a round {} certificate in round {round}", certificate.round());
+        }
+        previous_round = Some(*round);
+    }
+    Ok(())
+}
+
+/// Ensures the author of each of the given certificates is a member of the committee for the certificate's round.
+fn ensure_committee_authors<N: Network>(
+    rounds: &BTreeMap<u64, IndexSet<BatchCertificate<N>>>,
+    committee_for_round: impl Fn(u64) -> Result<Committee<N>>,
+) -> Result<()> {
+    for (round, certificates) in rounds {
+        // Retrieve the committee for the round.
+        let committee = committee_for_round(*round)?;
+        // Ensure each author is a member of the committee.
+        for certificate in certificates {
+            if !committee.is_committee_member(certificate.author()) {
+                bail!("Certificate author {} is not a committee member in round {round}", certificate.author());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Ensures the author and signers of the given certificate are members of the given committee,
+/// and that they reach the quorum threshold.
+fn ensure_certificate_quorum<N: Network>(certificate: &BatchCertificate<N>, committee: &Committee<N>) -> Result<()> {
+    // Retrieve the round.
+    let round = certificate.round();
+    // Collect the author and signers.
+    let signers = std::iter::once(certificate.author())
+        .chain(certificate.signatures().map(|signature| signature.to_address()))
+        .collect::<HashSet<_>>();
+    // Ensure the signers are committee members.
+    if let Some(signer) = signers.iter().find(|signer| !committee.is_committee_member(**signer)) {
+        bail!("Signer '{signer}' is not in the committee for round {round}");
+    }
+    // Ensure the signers reach the quorum threshold.
+    if !committee.is_quorum_threshold_reached(&signers) {
+        bail!("Signers of certificate {} did not reach quorum threshold for round {round}", fmt_id(certificate.id()));
+    }
+    Ok(())
+}
+
+/// Ensures the certificates in each of the given rounds reach quorum in the committee for their round.
+fn ensure_subdag_quorums<N: Network>(
+    rounds: &BTreeMap<u64, IndexSet<BatchCertificate<N>>>,
+    committee_for_round: impl Fn(u64) -> Result<Committee<N>>,
+) -> Result<()> {
+    for (round, certificates) in rounds {
+        // Retrieve the committee for the round.
+        let committee = committee_for_round(*round)?;
+        // Ensure each certificate reaches quorum.
+        for certificate in certificates {
+            ensure_certificate_quorum(certificate, &committee)?;
+        }
+    }
+    Ok(())
+}
+
+/// Ensures the given fee (in microcredits) covers the given cost (in microcredits).
+fn ensure_fee_covers_cost(fee: u64, cost: u64) -> Result<()> {
+    if fee < cost {
+        bail!("Insufficient fee - the fee of {fee} microcredits is short of the cost {cost} by {}", cost - fee);
+    }
+    Ok(())
+}
+
+/// Returns the number of transactions per second, given the number of transactions over the given time span
+/// (in seconds). If the time span is not positive, then zero is returned.
+fn transactions_per_second(num_transactions: u64, time_span: i64) -> f64 {
+    match time_span > 0 {
+        true => num_transactions as f64 / time_span as f64,
+        false => 0.0,
+    }
+}
+
+/// Returns the mean round delta between consecutive blocks, given the rounds of the blocks in height order.
+/// If fewer than two rounds are given, then `None` is returned.
+fn average_round_delta(rounds: &[u64]) -> Option<f64> {
+    // Ensure there is at least one interval.
+    let num_intervals = rounds.len().checked_sub(1).filter(|num_intervals| *num_intervals > 0)?;
+    // Sum the round deltas between consecutive blocks.
+    let total_delta: u64 = rounds.windows(2).map(|pair| pair[1].saturating_sub(pair[0])).sum();
+    // Return the average round delta.
+    Some(total_delta as f64 / num_intervals as f64)
+}
+
+/// Returns the sum of the given fees (in microcredits), failing if the sum overflows.
+fn sum_fees(fees: impl IntoIterator<Item = Result<u64>>) -> Result<u64> {
+    fees.into_iter()
+        .try_fold(0u64, |total, fee| total.checked_add(fee?).ok_or_else(|| anyhow!("The total fees overflowed")))
+}
+
+/// Ensures the given block timestamp is strictly greater than the given previous block timestamp.
+fn ensure_timestamp_increasing(previous_timestamp: i64, timestamp: i64) -> Result<()> {
+    if timestamp <= previous_timestamp {
+        bail!("Block timestamp {timestamp} is not after the previous block timestamp {previous_timestamp}");
+    }
+    Ok(())
+}
+
+/// Returns the first anchor round after the given round. Anchors are elected in every even round.
+fn next_anchor_round(round: u64) -> u64 {
+    round.saturating_add(2) - round % 2
+}
+
+/// Returns the fraction of the given rounds for which `authored` holds.
+fn uptime_over_rounds(rounds: Range<u64>, authored: impl Fn(u64) -> Result<bool>) -> Result<f64> {
+    // Ensure the rounds are non-empty.
+    if rounds.is_empty() {
+        bail!("Cannot compute the uptime over an empty round range {rounds:?}");
+    }
+    // Count the rounds in which the validator authored a certificate.
+    let num_rounds = rounds.end - rounds.start;
+    let mut num_authored = 0u64;
+    for round in rounds {
+        if authored(round)? {
+            num_authored += 1;
+        }
+    }
+    Ok(num_authored as f64 / num_rounds as f64)
+}
+
+/// Returns up to `page_size` of the `(height, item)` pairs yielded by `items_at` for the heights below `before_height`,
+/// scanning backward over at most `MAX_BLOCK_RANGE` heights, along with the cursor to resume from (if any heights
+/// remain). The items of a height are not split across pages, so a single height may exceed the page size.
+fn page_backward<T>(
+    before_height: u32,
+    page_size: u32,
+    items_at: impl Fn(u32) -> Result<Vec<T>>,
+) -> Result<(Vec<(u32, T)>, Option<u32>)> {
+    let page_size = page_size as usize;
+    let mut page = Vec::new();
+    // Determine the lowest height to scan.
+    let lowest_height = before_height.saturating_sub(MAX_BLOCK_RANGE);
+    for height in (lowest_height..before_height).rev() {
+        // Retrieve the items at the height.
+        let items = items_at(height)?;
+        // Stop if the items do not fit in a non-empty page, and resume from this height.
+        if !page.is_empty() && page.len() + items.len() > page_size {
+            return Ok((page, Some(height + 1)));
+        }
+        page.extend(items.into_iter().map(|item| (height, item)));
+        // Stop once the page is full.
+        if page.len() >= page_size {
+            return Ok((page, (height > 0).then_some(height)));
+        }
+    }
+    Ok((page, (lowest_height > 0).then_some(lowest_height)))
+}
+
+/// Ensures the given leader reward credited in a block matches the given expected leader reward. As the reward
+/// of each staker is rounded down, the credited reward may fall short by up to one microcredit per staker.
+fn ensure_leader_reward(expected: u64, credited: u64, num_stakers: u64) -> Result<()> {
+    if credited > expected || credited < expected.saturating_sub(num_stakers) {
+        bail!("Expected a leader reward of {expected} microcredits, found {credited} microcredits");
+    }
+    Ok(())
+}
+
+/// Ensures the given expected root matches the given root found in the block at the given height.
+fn ensure_root_matches<T: PartialEq + fmt::Display>(name: &str, height: u32, expected: T, found: T) -> Result<()> {
+    if expected != found {
+        bail!("Invalid {name} in block {height} - expected {expected}, found {found}");
+    }
+    Ok(())
+}
+
+/// Returns the share of the given amount that is proportional to `part` out of `total`, rounded down.
+fn proportional_share(amount: u64, part: u64, total: u64) -> u64 {
+    match total {
+        0 => 0,
+        total => (amount as u128 * part as u128 / total as u128) as u64,
+    }
+}
+
+/// Returns the expected cumulative proof target of a block, given the cumulative proof target of the previous block,
+/// the combined proof target of the block's solutions, and the block's coinbase target. The cumulative proof target
+/// resets to zero once it reaches the coinbase target.
+fn expected_cumulative_proof_target(previous_cumulative: u128, combined: u128, coinbase_target: u64) -> u128 {
+    match previous_cumulative.saturating_add(combined) {
+        cumulative if cumulative >= coinbase_target as u128 => 0,
+        cumulative => cumulative,
+    }
+}
+
+/// Ensures the given cumulative proof target did not decrease from the given previous cumulative proof target,
+/// unless it reset to zero upon reaching the coinbase target, which requires the block to have solutions.
+fn ensure_monotonic_target(previous_cumulative: u128, cumulative: u128, has_solutions: bool) -> Result<()> {
+    if cumulative < previous_cumulative && !(cumulative == 0 && has_solutions) {
+        bail!("Cumulative proof target decreased from {previous_cumulative} to {cumulative}");
+    }
+    Ok(())
+}
+
+/// Ensures the given ratifications contain exactly the given block reward and puzzle reward.
+fn ensure_rewards_match<'a, N: Network>(
+    block_reward: u64,
+    puzzle_reward: u64,
+    ratifications: impl IntoIterator<Item = &'a Ratify<N>>,
+) -> Result<()> {
+    // Collect the recorded rewards.
+    let (mut block_rewards, mut puzzle_rewards) = (Vec::new(), Vec::new());
+    for ratification in ratifications {
+        match ratification {
+            Ratify::BlockReward(amount) => block_rewards.push(*amount),
+            Ratify::PuzzleReward(amount) => puzzle_rewards.push(*amount),
+            _ => (),
+        }
+    }
+    // Ensure the recorded rewards match the expected rewards.
+    if block_rewards != [block_reward] {
+        bail!("Invalid block reward - expected {block_reward}, found {block_rewards:?}");
+    }
+    if puzzle_rewards != [puzzle_reward] {
+        bail!("Invalid puzzle reward - expected {puzzle_reward}, found {puzzle_rewards:?}");
+    }
+    Ok(())
+}
+
+/// Returns the lowest height up to `latest_height` for which `contains` holds, assuming it holds for a suffix
+/// of the heights (i.e. the heights below it have been pruned). If it holds for none, `latest_height` is returned.
+fn find_earliest_height(latest_height: u32, contains: impl Fn(u32) -> bool) -> u32 {
+    // Initialize the search bounds.
+    let (mut low, mut high) = (0, latest_height);
+    // Perform a binary search.
+    while low < high {
+        let middle = low + (high - low) / 2;
+        match contains(middle) {
+            true => high = middle,
+            false => low = middle + 1,
+        }
+    }
+    low
+}
+
+/// Returns the heights in the given range for which `contains` does not hold.
+fn missing_heights(heights: Range<u32>, contains: impl Fn(u32) -> bool) -> Vec<u32> {
+    heights.filter(|height| !contains(*height)).collect()
+}
+
+/// Ensures the given round advances past the latest round, by at most `MAX_ROUND_ADVANCE_PER_MEMBER`
+/// rounds for each of the given number of committee members.
+fn ensure_round_advance(latest_round: u64, round: u64, num_members: usize) -> Result<()> {
+    // Ensure the round advances past the latest round.
+    if round <= latest_round {
+        bail!("Round {round} does not advance past the latest round {latest_round}");
+    }
+    // Ensure the round does not advance too far past the latest round.
+    let max_advance = MAX_ROUND_ADVANCE_PER_MEMBER.saturating_mul(num_members.max(1) as u64);
+    if round - latest_round > max_advance {
+        bail!("Round {round} advances more than {max_advance} rounds past the latest round {latest_round}");
+    }
+    Ok(())
+}
+
+/// Ensures each of the given committee members has a non-zero stake, and that their stakes sum to the total stake.
+fn ensure_consistent_stake<N: Network>(members: &IndexMap<Address<N>, (u64, bool)>, total_stake: u64) -> Result<()> {
+    // Sum the member stakes.
+    let mut stake = 0u64;
+    for (address, (member_stake, _)) in members {
+        // Ensure the member has a non-zero stake.
+        if *member_stake == 0 {
+            bail!("Committee member {address} has no stake");
+        }
+        stake = stake.checked_add(*member_stake).ok_or_else(|| anyhow!("Committee stake overflowed"))?;
+    }
+    // Ensure the sum of the member stakes matches the total stake.
+    if stake != total_stake {
+        bail!("Committee total stake {total_stake} does not match the sum of member stakes {stake}");
+    }
+    Ok(())
+}
+
+/// Returns the checkpoint hash that results from accumulating the given block hash into the given checkpoint hash.
+fn accumulate_checkpoint_hash<N: Network>(checkpoint_hash: Field<N>, block_hash: N::BlockHash) -> Result<Field<N>> {
+    N::hash_psd2(&[checkpoint_hash, *block_hash])
+}
+
+/// A core ledger service.
+pub struct CoreLedgerService<N: Network, C: ConsensusStorage<N>> {
+    ledger: Ledger<N, C>,
+    /// The verifier for prover solutions.
+    solution_verifier: Arc<dyn SolutionVerifier<N>>,
+    /// The set of programs that transactions may deploy or execute, if restricted.
+    program_allowlist: Option<HashSet<ProgramID<N>>>,
+    /// The maximum size of a solution transmission.
+    max_solution_bytes: usize,
+    /// The maximum size of a transaction transmission.
+    max_transaction_bytes: usize,
+    /// The `(total supply, circulating supply)`, and the hash of the latest block they were computed at.
+    supply_cache: RwLock<Option<(N::BlockHash, (u64, u64))>>,
+    /// The addresses in the `bonded` mapping, and the hash of the latest block they were retrieved at.
+    bonded_addresses_cache: RwLock<Option<(N::BlockHash, Vec<Address<N>>)>>,
+    /// The epoch challenge, and the epoch number it is for.
+    epoch_challenge_cache: RwLock<Option<(u32, EpochChallenge<N>)>>,
+    /// The checkpoint hashes at every `CHECKPOINT_HASH_INTERVAL` heights, along with the block hash at that height.
+    checkpoint_cache: RwLock<BTreeMap<u32, (N::BlockHash, Field<N>)>>,
+    /// The instants at which transactions were verified, within the throughput window.
+    verified_transactions: Mutex<VecDeque<Instant>>,
+    /// The instants at which solutions were verified, within the throughput window.
+    verified_solutions: Mutex<VecDeque<Instant>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> CoreLedgerService<N, C> {
+    /// Initializes a new core ledger service.
+    pub fn new(ledger: Ledger<N, C>, shutdown: Arc<AtomicBool>) -> Self {
+        let solution_verifier: Arc<dyn SolutionVerifier<N>> =
+            Arc::new(ledger.coinbase_puzzle().coinbase_verifying_key().clone());
+        Self {
+            ledger,
+            solution_verifier,
+            program_allowlist: None,
+            max_solution_bytes: DEFAULT_MAX_SOLUTION_BYTES,
+            max_transaction_bytes: DEFAULT_MAX_TRANSACTION_BYTES,
+            supply_cache: Default::default(),
+            bonded_addresses_cache: Default::default(),
+            epoch_challenge_cache: Default::default(),
+            checkpoint_cache: Default::default(),
+            verified_transactions: Default::default(),
+            verified_solutions: Default::default(),
+            shutdown,
+        }
+    }
+
+    /// Sets the maximum sizes of solution and transaction transmissions.
+    pub fn with_transmission_limits(mut self, max_solution_bytes: usize, max_transaction_bytes: usize) -> Self {
+        self.max_solution_bytes = max_solution_bytes;
+        self.max_transaction_bytes = max_transaction_bytes;
+        self
+    }
+
+    /// Replaces the verifier for prover solutions, which defaults to the coinbase verifying key.
+    pub fn with_solution_verifier(mut self, solution_verifier: Arc<dyn SolutionVerifier<N>>) -> Self {
+        self.solution_verifier = solution_verifier;
+        self
+    }
+
+    /// Restricts the programs that transactions may deploy or execute to the given allowlist.
+    /// Note: The allowlist must include `credits.aleo` for credit transfers to be accepted.
+    pub fn with_program_allowlist(mut self, program_allowlist: HashSet<ProgramID<N>>) -> Self {
+        self.program_allowlist = Some(program_allowlist);
+        self
+    }
+
+    /// Ensures the given transaction only deploys or executes programs in the allowlist, if one is set.
+    fn check_program_allowlist(&self, transaction: &Transaction<N>) -> Result<()> {
+        // If there is no allowlist, then all programs are permitted.
+        let Some(program_allowlist) = &self.program_allowlist else {
+            return Ok(());
+        };
+        match transaction {
+            Transaction::Deploy(_, _, deployment, _) => {
+                // Ensure the deployed program is in the allowlist.
+                if !program_allowlist.contains(deployment.program_id()) {
+                    bail!("Invalid transaction - program '{}' is not in the allowlist", deployment.program_id());
+                }
+            }
+            Transaction::Execute(_, execution, _) => {
+                // Ensure each executed program is in the allowlist.
+                for transition in execution.transitions() {
+                    if !program_allowlist.contains(transition.program_id()) {
+                        bail!("Invalid transaction - program '{}' is not in the allowlist", transition.program_id());
+                    }
+                }
+            }
+            Transaction::Fee(..) => (),
+        }
+        Ok(())
+    }
+
+    /// Returns a root over the given set of transmission IDs.
+    ///
+    /// The transmission IDs are put in a canonical order, by sorting their byte encoding and deduplicating it,
+    /// and each one is hashed into a leaf that is accumulated into the root with Poseidon. As such, the root only
+    /// depends on the set of transmission IDs, and not on the order in which a node received or stores them,
+    /// so auditors on different nodes compute the same root for the transmission IDs of the same batch.
+    pub fn transmission_set_root(ids: &[TransmissionID<N>]) -> Result<Field<N>> {
+        // Serialize the transmission IDs.
+        let mut leaves = ids.iter().map(|id| id.to_bytes_le()).collect::<Result<Vec<_>>>()?;
+        // Sort and deduplicate the serialized transmission IDs.
+        leaves.sort_unstable();
+        leaves.dedup();
+        // Accumulate the leaves into the root.
+        leaves.iter().try_fold(Field::zero(), |root, leaf| N::hash_psd2(&[root, N::hash_bhp1024(&leaf.to_bits_le())?]))
+    }
+
+    /// Returns `true` if the given transmission ID and transmission are of the same type.
+    pub fn transmission_id_variant_matches(id: &TransmissionID<N>, transmission: &Transmission<N>) -> bool {
+        matches!(
+            (id, transmission),
+            (TransmissionID::Ratification, Transmission::Ratification)
+                | (TransmissionID::Solution(..), Transmission::Solution(..))
+                | (TransmissionID::Transaction(..), Transmission::Transaction(..))
+        )
+    }
+
+    /// Returns the height of the block that committed the given certificate ID,
+    /// or `None` if the certificate has not been committed to the ledger.
+    pub fn get_certificate_block_height(&self, certificate_id: &Field<N>) -> Result<Option<u32>> {
+        // Ensure the certificate exists in the ledger.
+        let Some(certificate) = self.ledger.get_batch_certificate(certificate_id)? else {
+            return Ok(None);
+        };
+        // A certificate is committed by the first block (at or above its round) whose subdag contains it.
+        for height in self.find_first_height_at_or_above_round(certificate.round())?..=self.latest_block_height() {
+            // If the Ctrl-C handler registered the signal, then stop the search.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the search for certificate {} - The node is shutting down", fmt_id(certificate_id));
+            }
+            // Retrieve the subdag of the block.
+            if let Authority::Quorum(subdag) = self.ledger.get_block(height)?.authority() {
+                // Return the height if the subdag contains the certificate.
+                if subdag.certificate_ids().any(|id| id == *certificate_id) {
+                    return Ok(Some(height));
+                }
+            }
+        }
+        bail!("Certificate {} exists in the ledger, but was not found in any block", fmt_id(certificate_id))
+    }
+
+    /// Returns the height of the first block whose round is at or above the given round.
+    /// If no such block exists, the height after the latest block is returned.
+    fn find_first_height_at_or_above_round(&self, round: u64) -> Result<u32> {
+        // Initialize the search bounds.
+        let (mut low, mut high) = (0, self.latest_block_height().saturating_add(1));
+        // Perform a binary search, as block rounds are strictly increasing in the block height.
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.ledger.get_header(middle)?.round() < round {
+                true => low = middle + 1,
+                false => high = middle,
+            }
+        }
+        Ok(low)
+    }
+
+    /// Returns the elapsed time (in seconds) from the block at `height_a` to the block at `height_b`.
+    /// The elapsed time is negative if the block at `height_b` precedes the block at `height_a`.
+    pub fn inter_block_time(&self, height_a: u32, height_b: u32) -> Result<i64> {
+        // Retrieve the timestamps of the blocks.
+        let timestamp_a = self.ledger.get_header(height_a)?.timestamp();
+        let timestamp_b = self.ledger.get_header(height_b)?.timestamp();
+        // Return the elapsed time.
+        Ok(timestamp_b.saturating_sub(timestamp_a))
+    }
+
+    /// Returns the average time (in seconds) between consecutive blocks in the given block range.
+    /// The range is inclusive of the start and exclusive of the end, and must contain at least two blocks.
+    pub fn average_block_time(&self, heights: Range<u32>) -> Result<f64> {
+        // Ensure the block range is valid.
+        self.ensure_valid_block_range(&heights)?;
+        // Ensure the block range contains at least one interval.
+        let num_intervals = heights.len().saturating_sub(1);
+        if num_intervals == 0 {
+            bail!("Block range {heights:?} must contain at least two blocks");
+        }
+        // The sum of the intervals between consecutive blocks is the elapsed time across the range.
+        let elapsed_time = self.inter_block_time(heights.start, heights.end - 1)?;
+        // Return the average block time.
+        Ok(elapsed_time as f64 / num_intervals as f64)
+    }
+
+    /// Returns the average number of rounds between consecutive committed blocks in the given block range.
+    /// The range is inclusive of the start and exclusive of the end, and must contain at least two blocks.
+    ///
+    /// A rising value indicates that blocks are taking more rounds to reach finality.
+    pub fn average_rounds_per_block(&self, heights: Range<u32>) -> Result<f64> {
+        // Ensure the block range is valid.
+        self.ensure_valid_block_range(&heights)?;
+        // Retrieve the rounds of the blocks.
+        let rounds =
+            heights.clone().map(|height| Ok(self.ledger.get_header(height)?.round())).collect::<Result<Vec<_>>>()?;
+        // Return the average round delta.
+        average_round_delta(&rounds).ok_or_else(|| anyhow!("Block range {heights:?} must contain at least two blocks"))
+    }
+
+    /// Returns the total fees (in microcredits) paid by the confirmed transactions in the given block range.
+    /// The range is inclusive of the start and exclusive of the end.
+    pub fn total_fees_collected(&self, heights: Range<u32>) -> Result<u64> {
+        // Ensure the block range is valid.
+        self.ensure_valid_block_range(&heights)?;
+        // Sum the fees of each block.
+        let mut total = 0u64;
+        for height in heights {
+            // If the Ctrl-C handler registered the signal, then stop the sum.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the fee total at block {height} - The node is shutting down");
+            }
+            // Retrieve the transactions of the block.
+            let transactions = self.ledger.get_block(height)?.transactions().clone();
+            // Sum the fees of the transactions.
+            let fees = transactions.iter().map(|transaction| Ok(*transaction.transaction().fee_amount()?));
+            total = sum_fees([Ok(total), sum_fees(fees)])?;
+        }
+        Ok(total)
+    }
+
+    /// Ensures the given block range is well-formed, bounded by `MAX_BLOCK_RANGE`,
+    /// and that the node is not shutting down.
+    fn ensure_valid_block_range(&self, heights: &Range<u32>) -> Result<()> {
+        // If the Ctrl-C handler registered the signal, then skip the query.
         if self.shutdown.load(Ordering::Relaxed) {
-            bail!("Skipping advancing to block {} - The node is shutting down", block.height());
+            bail!("Skipping the query for block range {heights:?} - The node is shutting down");
+        }
+        // Ensure the start height is at or below the end height.
+        if heights.start > heights.end {
+            bail!("Invalid block range {heights:?}");
+        }
+        // Ensure the block range is bounded.
+        if heights.len() > MAX_BLOCK_RANGE as usize {
+            bail!("Cannot query more than {MAX_BLOCK_RANGE} blocks at once (requested {})", heights.len());
         }
-        // Advance to the next block.
-        self.ledger.advance_to_next_block(block)?;
-        tracing::info!("\n\nAdvanced to block {} at round {} - {}\n", block.height(), block.round(), block.hash());
         Ok(())
     }
+
+    /// Streams the confirmed transactions in the given block range, along with their block height, to the given sender.
+    /// The range is inclusive of the start and exclusive of the end.
+    ///
+    /// The stream stops early if the node is shutting down, if the receiver is dropped, or after sending an error.
+    pub async fn stream_confirmed_transactions(
+        &self,
+        heights: Range<u32>,
+        tx: mpsc::Sender<Result<(u32, ConfirmedTransaction<N>)>>,
+    ) {
+        for height in heights {
+            // If the Ctrl-C handler registered the signal, then stop streaming.
+            if self.shutdown.load(Ordering::Relaxed) {
+                let _ = tx.send(Err(anyhow!("Stopped streaming at block {height} - The node is shutting down"))).await;
+                return;
+            }
+            // Retrieve the transactions of the block.
+            let transactions = match self.ledger.get_transactions(height) {
+                Ok(transactions) => transactions,
+                Err(error) => {
+                    let _ = tx.send(Err(error)).await;
+                    return;
+                }
+            };
+            // Send each confirmed transaction, waiting for capacity in the channel.
+            for transaction in transactions.into_iter() {
+                if tx.send(Ok((height, transaction))).await.is_err() {
+                    // The receiver was dropped, so stop streaming.
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Returns the IDs of the previous certificates referenced by the given certificate that are not in the ledger.
+    pub fn missing_certificate_parents(&self, certificate: &BatchCertificate<N>) -> Result<Vec<Field<N>>> {
+        let mut missing_parents = Vec::new();
+        // Iterate over the previous certificate IDs.
+        for previous_certificate_id in certificate.previous_certificate_ids() {
+            // Append the previous certificate ID, if it is not in the ledger.
+            if !self.contains_certificate(previous_certificate_id)? {
+                missing_parents.push(*previous_certificate_id);
+            }
+        }
+        Ok(missing_parents)
+    }
+
+    /// Returns the committed certificates for the given round.
+    pub fn get_certificates_for_round(&self, round: u64) -> Result<Vec<BatchCertificate<N>>> {
+        let mut certificates = Vec::new();
+        // Certificates are committed by the blocks at or above their round, up to the GC depth.
+        for height in self.find_first_height_at_or_above_round(round)?..=self.latest_block_height() {
+            // If the Ctrl-C handler registered the signal, then stop the search.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the search for round {round} certificates - The node is shutting down");
+            }
+            // Retrieve the block.
+            let block = self.ledger.get_block(height)?;
+            // Stop once the block is beyond the GC depth of the round.
+            if block.round() > round.saturating_add(MAX_COMMIT_DELAY_IN_ROUNDS) {
+                break;
+            }
+            // Append the certificates for the round from the block's subdag.
+            if let Authority::Quorum(subdag) = block.authority() {
+                if let Some(round_certificates) = subdag.get(&round) {
+                    certificates.extend(round_certificates.iter().cloned());
+                }
+            }
+        }
+        Ok(certificates)
+    }
+
+    /// Returns the stake-weighted median timestamp of the committed certificates for the given round.
+    pub fn weighted_median_timestamp(&self, round: u64) -> Result<i64> {
+        // Retrieve the committed certificates for the round.
+        let certificates = self.get_certificates_for_round(round)?;
+        // Retrieve the committee for the round.
+        let committee = self.get_previous_committee_for_round(round)?;
+        // Weigh the timestamp of each certificate by the stake of its author.
+        let timestamps =
+            certificates.iter().map(|certificate| (certificate.timestamp(), committee.get_stake(certificate.author())));
+        // Compute the weighted median.
+        match weighted_median(timestamps) {
+            Some(timestamp) => Ok(timestamp),
+            None => bail!("No committed certificates with stake found for round {round}"),
+        }
+    }
+
+    /// Returns the stake-weighted average offset (in seconds) of the timestamps of the committed certificates
+    /// in the given round from the earliest of them. A high value indicates the high-stake validators are slow.
+    pub fn weighted_certificate_latency(&self, round: u64) -> Result<f64> {
+        // Retrieve the committed certificates for the round.
+        let certificates = self.get_certificates_for_round(round)?;
+        // Retrieve the committee for the round.
+        let committee = self.get_previous_committee_for_round(round)?;
+        // Weigh the timestamp of each certificate by the stake of its author.
+        let timestamps =
+            certificates.iter().map(|certificate| (certificate.timestamp(), committee.get_stake(certificate.author())));
+        // Compute the weighted average offset.
+        match weighted_average_offset(timestamps) {
+            Some(latency) => Ok(latency),
+            None => bail!("No committed certificates with stake found for round {round}"),
+        }
+    }
+
+    /// Returns the addresses that signed the anchor (leader) certificate of the block at the given height,
+    /// starting with the author of the anchor certificate.
+    pub fn anchor_signers(&self, height: u32) -> Result<Vec<Address<N>>> {
+        // Retrieve the subdag of the block.
+        let Authority::Quorum(subdag) = self.ledger.get_block(height)?.authority().clone() else {
+            bail!("Block {height} is not a quorum block");
+        };
+        // Retrieve the anchor certificate.
+        let anchor = subdag.leader_certificate();
+        // Collect the author and signers of the anchor certificate.
+        let mut signers = vec![anchor.author()];
+        signers.extend(anchor.signatures().map(|signature| signature.to_address()));
+        Ok(signers)
+    }
+
+    /// Returns the fraction of the committee's total stake that authored a committed certificate in the given round.
+    pub fn participation_rate(&self, round: u64) -> Result<f64> {
+        // Retrieve the committee for the round.
+        let committee = self.get_previous_committee_for_round(round)?;
+        // Retrieve the authors of the committed certificates for the round.
+        let authors =
+            self.get_certificates_for_round(round)?.iter().map(|certificate| certificate.author()).collect::<Vec<_>>();
+        // Return the fraction of stake held by the authors.
+        Ok(stake_fraction(&committee, authors))
+    }
+
+    /// Returns the members of the committee for the given round that did not author a committed certificate in it.
+    pub fn absent_validators_in_round(&self, round: u64) -> Result<Vec<Address<N>>> {
+        // Retrieve the committee for the round.
+        let committee = self.get_previous_committee_for_round(round)?;
+        // Retrieve the authors of the committed certificates for the round.
+        let authors =
+            self.get_certificates_for_round(round)?.iter().map(|certificate| certificate.author()).collect::<Vec<_>>();
+        // Return the members that did not author a certificate.
+        Ok(absent_members(&committee, authors))
+    }
+
+    /// Ensures the transactions in the given block do not spend the same serial number more than once.
+    pub fn check_intra_block_conflicts(&self, block: &Block<N>) -> Result<()> {
+        ensure_unique_serial_numbers(block.transactions().iter().map(|transaction| transaction.transaction()))
+            .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Ensures the transactions in the given block do not spend a serial number that was spent in an earlier block.
+    pub fn check_no_double_spend(&self, block: &Block<N>) -> Result<()> {
+        let transactions = block.transactions().iter().map(|transaction| transaction.transaction());
+        ensure_unspent_serial_numbers(transactions, |serial_number| self.ledger.contains_serial_number(serial_number))
+            .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Returns the most recent round, within `search_back` rounds of the latest round,
+    /// in which the given address authored a committed certificate, or `None` if it did not.
+    pub fn last_authored_round(&self, author: &Address<N>, search_back: u64) -> Result<Option<u64>> {
+        // Determine the earliest round to search.
+        let earliest_round = self.latest_round().saturating_sub(search_back);
+        // Initialize the most recent authored round.
+        let mut last_authored_round = None;
+        // Iterate backwards through the blocks.
+        for height in (0..=self.latest_block_height()).rev() {
+            // If the Ctrl-C handler registered the signal, then stop the search.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the search for the last authored round - The node is shutting down");
+            }
+            // Retrieve the block.
+            let block = self.ledger.get_block(height)?;
+            // Stop once the block (and thus its certificates) precedes the search window or the authored round.
+            if block.round() < earliest_round || Some(block.round()) <= last_authored_round {
+                break;
+            }
+            // Update the most recent authored round from the block's subdag.
+            if let Authority::Quorum(subdag) = block.authority() {
+                for (round, certificates) in subdag.iter() {
+                    if *round >= earliest_round
+                        && certificates.iter().any(|certificate| certificate.author() == *author)
+                    {
+                        last_authored_round = last_authored_round.max(Some(*round));
+                    }
+                }
+            }
+        }
+        Ok(last_authored_round)
+    }
+
+    /// Ensures the given transmission is within the size limit for its type.
+    ///
+    /// Only serialized transmissions are measured, as measuring a deserialized transmission would require
+    /// serializing it again. A deserialized transmission was already decoded by this node, and is not checked.
+    pub fn check_transmission_limits(&self, id: &TransmissionID<N>, transmission: &Transmission<N>) -> Result<()> {
+        // Retrieve the size of the transmission, and the size limit for its type.
+        let (size, max_size) = match transmission {
+            Transmission::Ratification => return Ok(()),
+            Transmission::Solution(solution) => (buffer_size(solution), self.max_solution_bytes),
+            Transmission::Transaction(transaction) => (buffer_size(transaction), self.max_transaction_bytes),
+        };
+        let Some(size) = size else {
+            return Ok(());
+        };
+        // Ensure the transmission is within the size limit.
+        if size > max_size {
+            bail!("Transmission '{}' is too large ({size} bytes, limit is {max_size} bytes)", fmt_id(id));
+        }
+        Ok(())
+    }
+
+    /// Returns the block hashes in the given block range.
+    /// The range is inclusive of the start and exclusive of the end.
+    pub fn get_block_hashes(&self, heights: Range<u32>) -> Result<Vec<N::BlockHash>> {
+        // Ensure the block range is valid.
+        self.ensure_valid_block_range(&heights)?;
+        // Retrieve the block hashes.
+        heights.map(|height| self.ledger.get_hash(height)).collect()
+    }
+
+    /// Returns the highest height in the given `(height, hash)` locators whose hash matches the local block hash,
+    /// or `None` if none of the locators match. The locators must be in strictly descending order of height.
+    pub fn find_fork_point(&self, locators: &[(u32, N::BlockHash)]) -> Result<Option<u32>> {
+        // Ensure the locators are in strictly descending order of height.
+        if locators.windows(2).any(|pair| pair[0].0 <= pair[1].0) {
+            bail!("Block locators must be in strictly descending order of height");
+        }
+        // Find the first (i.e. highest) locator that matches the local block hash.
+        for (height, hash) in locators {
+            if self.contains_block_height(*height) && self.ledger.get_hash(*height)? == *hash {
+                return Ok(Some(*height));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the solutions accepted in the block at the given height.
+    pub fn get_block_solutions(&self, height: u32) -> Result<Vec<ProverSolution<N>>> {
+        // Retrieve the block.
+        let block = self.ledger.get_block(height)?;
+        // Return the accepted solutions, if the block has any.
+        Ok(block.solutions().map(|solutions| solutions.values().cloned().collect()).unwrap_or_default())
+    }
+
+    /// Returns the total supply of public credits (in microcredits), which is the sum of the public balances,
+    /// bonded amounts, and unbonding amounts. Note: Credits held in private records are not included.
+    ///
+    /// This requires a full scan of the relevant `credits.aleo` mappings, so the result is cached
+    /// until the next block is committed.
+    pub fn total_supply(&self) -> Result<u64> {
+        Ok(self.get_supply()?.0)
+    }
+
+    /// Returns the circulating supply of public credits (in microcredits), which is the sum of the public balances.
+    /// Note: Credits held in private records, and bonded or unbonding amounts, are not included.
+    ///
+    /// This requires a full scan of the relevant `credits.aleo` mappings, so the result is cached
+    /// until the next block is committed.
+    pub fn circulating_supply(&self) -> Result<u64> {
+        Ok(self.get_supply()?.1)
+    }
+
+    /// Returns the `(total supply, circulating supply)`, computing it if the cache is stale.
+    fn get_supply(&self) -> Result<(u64, u64)> {
+        // Retrieve the latest block hash.
+        let latest_hash = self.ledger.latest_hash();
+        // Return the cached supply, if it was computed at the latest block.
+        if let Some((hash, supply)) = *self.supply_cache.read() {
+            if hash == latest_hash {
+                return Ok(supply);
+            }
+        }
+        // Compute the circulating supply from the public balances.
+        let mut circulating_supply = 0u64;
+        for (_, value) in self.get_credits_mapping("account")? {
+            circulating_supply = circulating_supply.saturating_add(value_to_u64(&value)?);
+        }
+        // Compute the total supply by adding the bonded and unbonding amounts.
+        let mut total_supply = circulating_supply;
+        for mapping in ["bonded", "unbonding"] {
+            for (_, value) in self.get_credits_mapping(mapping)? {
+                total_supply = total_supply.saturating_add(struct_member_to_u64(&value, "microcredits")?);
+            }
+        }
+        // Cache the supply.
+        *self.supply_cache.write() = Some((latest_hash, (total_supply, circulating_supply)));
+        Ok((total_supply, circulating_supply))
+    }
+
+    /// Returns the addresses of all stakers, from the `credits.aleo/bonded` mapping.
+    ///
+    /// This requires a full scan of the mapping, so the result is cached until the next block is committed.
+    pub fn bonded_addresses(&self) -> Result<Vec<Address<N>>> {
+        // Retrieve the latest block hash.
+        let latest_hash = self.ledger.latest_hash();
+        // Return the cached addresses, if they were retrieved at the latest block.
+        if let Some((hash, addresses)) = &*self.bonded_addresses_cache.read() {
+            if *hash == latest_hash {
+                return Ok(addresses.clone());
+            }
+        }
+        // Retrieve the addresses from the bonded mapping.
+        let mut addresses = Vec::new();
+        for (key, _) in self.get_credits_mapping("bonded")? {
+            addresses.push(value_to_address(&Value::Plaintext(key))?);
+        }
+        // Cache the addresses.
+        *self.bonded_addresses_cache.write() = Some((latest_hash, addresses.clone()));
+        Ok(addresses)
+    }
+
+    /// Returns the entries of the given `credits.aleo` mapping.
+    fn get_credits_mapping(&self, mapping: &str) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        // If the Ctrl-C handler registered the signal, then skip the scan.
+        if self.shutdown.load(Ordering::Relaxed) {
+            bail!("Skipping the scan of the '{mapping}' mapping - The node is shutting down");
+        }
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let mapping_name = Identifier::from_str(mapping)?;
+        self.ledger.vm().finalize_store().get_mapping_confirmed(&program_id, &mapping_name)
+    }
+
+    /// Ensures the author and every signer of the given certificate produced a valid signature over its batch ID.
+    pub fn verify_certificate_signatures(&self, certificate: &BatchCertificate<N>) -> Result<()> {
+        // Retrieve the batch ID.
+        let batch_id = certificate.batch_id();
+        // Ensure the author signature is valid.
+        if !certificate.batch_header().signature().verify(&certificate.author(), &[batch_id]) {
+            bail!(
+                "Invalid signature from author '{}' for certificate {}",
+                certificate.author(),
+                fmt_id(certificate.id())
+            );
+        }
+        // Ensure each signer signature is valid.
+        for signature in certificate.signatures() {
+            let signer = signature.to_address();
+            if !signature.verify(&signer, &[batch_id]) {
+                bail!("Invalid signature from signer '{signer}' for certificate {}", fmt_id(certificate.id()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the author and signers of the given certificate are members of the committee for its round,
+    /// and that together they reach the quorum threshold.
+    pub fn verify_certificate_quorum(&self, certificate: &BatchCertificate<N>) -> Result<()> {
+        // Retrieve the committee for the round.
+        let committee = self.get_previous_committee_for_round(certificate.round())?;
+        // Ensure the signers are committee members that reach the quorum threshold.
+        ensure_certificate_quorum(certificate, &committee)
+    }
+
+    /// Ensures the given certificate has valid signatures from a quorum of the committee for its round.
+    pub fn verify_certificate(&self, certificate: &BatchCertificate<N>) -> Result<()> {
+        self.verify_certificate_quorum(certificate)?;
+        self.verify_certificate_signatures(certificate)
+    }
+
+    /// Returns the DAG of the committed certificates for the given round.
+    pub fn get_round_dag(&self, round: u64) -> Result<RoundDag<N>> {
+        Ok(RoundDag::new(round, &self.get_certificates_for_round(round)?))
+    }
+
+    /// Ensures the rounds of the given subdag are contiguous and ascending up to the anchor round,
+    /// and that each certificate is in its round.
+    pub fn check_subdag_round_order(&self, subdag: &Subdag<N>) -> Result<()> {
+        // Ensure the rounds are contiguous, and each certificate is in its round.
+        ensure_contiguous_rounds(subdag)?;
+        // Ensure the last round is the anchor round.
+        let anchor_round = subdag.leader_certificate().round();
+        match subdag.keys().last() {
+            Some(round) if *round == anchor_round => Ok(()),
+            _ => bail!("Subdag does not end at the anchor round {anchor_round}"),
+        }
+    }
+
+    /// Returns the rewards (in microcredits) earned by the given address in the block at the given height.
+    ///
+    /// The block reward is attributed in proportion to the address's stake in the committee for the block round,
+    /// which includes any stake delegated to it, and the puzzle reward is attributed in proportion to the proof
+    /// targets of the address's solutions in the block.
+    pub fn validator_reward_share(&self, address: &Address<N>, height: u32) -> Result<u64> {
+        // Retrieve the block.
+        let block = self.ledger.get_block(height)?;
+        // Retrieve the committee for the block round.
+        let committee = self.get_committee_for_round(block.round())?;
+        // Retrieve the proof targets of all solutions, and of the solutions from the address.
+        let (mut total_target, mut address_target) = (0u64, 0u64);
+        for solution in block.solutions().into_iter().flat_map(|solutions| solutions.values()) {
+            let target = solution.to_target()?;
+            total_target = total_target.saturating_add(target);
+            if solution.address() == *address {
+                address_target = address_target.saturating_add(target);
+            }
+        }
+        // Sum the address's share of each reward in the block.
+        let mut reward = 0u64;
+        for ratification in block.ratifications().iter() {
+            let share = match ratification {
+                Ratify::BlockReward(amount) => {
+                    proportional_share(*amount, committee.get_stake(*address), committee.total_stake())
+                }
+                Ratify::PuzzleReward(amount) => proportional_share(*amount, address_target, total_target),
+                _ => 0,
+            };
+            reward = reward.saturating_add(share);
+        }
+        Ok(reward)
+    }
+
+    /// Returns the rewards (in microcredits) earned by the given address in the given block range.
+    /// The range is inclusive of the start and exclusive of the end.
+    pub fn cumulative_rewards(&self, address: &Address<N>, heights: Range<u32>) -> Result<u64> {
+        // Ensure the block range is valid.
+        self.ensure_valid_block_range(&heights)?;
+        // Sum the rewards for each block.
+        let mut rewards = 0u64;
+        for height in heights {
+            // If the Ctrl-C handler registered the signal, then stop the summation.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the summation of rewards at block {height} - The node is shutting down");
+            }
+            rewards = rewards.saturating_add(self.validator_reward_share(address, height)?);
+        }
+        Ok(rewards)
+    }
+
+    /// Ensures the solutions in the given block are valid for the block's epoch and the previous block's
+    /// proof target, and that their combined proof target is correctly accounted against the previous block's
+    /// coinbase target. A block without solutions must carry over the previous cumulative proof target.
+    pub fn check_coinbase_target(&self, block: &Block<N>) -> Result<()> {
+        // The genesis block has no previous block.
+        if block.height() == 0 {
+            return Ok(());
+        }
+        // Retrieve the header of the previous block.
+        let previous_header = self.ledger.get_header(block.height() - 1)?;
+        // Retrieve the epoch challenge for the block.
+        let epoch_challenge = self.ledger.get_epoch_challenge(block.height())?;
+        // Ensure each solution is valid, and compute the combined proof target.
+        let mut combined_proof_target = 0u128;
+        for solution in block.solutions().into_iter().flat_map(|solutions| solutions.values()) {
+            if !self.solution_verifier.verify_solution(solution, &epoch_challenge, previous_header.proof_target())? {
+                bail!("Invalid solution '{}' in block {}", fmt_id(solution.commitment()), block.height());
+            }
+            combined_proof_target = combined_proof_target.saturating_add(solution.to_target()? as u128);
+        }
+        // Ensure the cumulative proof target accounts for the combined proof target.
+        let expected = expected_cumulative_proof_target(
+            previous_header.cumulative_proof_target(),
+            combined_proof_target,
+            previous_header.coinbase_target(),
+        );
+        if block.cumulative_proof_target() != expected {
+            bail!(
+                "Invalid cumulative proof target in block {} - expected {expected}, found {}",
+                block.height(),
+                block.cumulative_proof_target()
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensures the cumulative proof target of the given block did not decrease from that of the previous block.
+    /// The cumulative proof target may only decrease by resetting to zero, once the block's solutions reach
+    /// the coinbase target.
+    pub fn check_cumulative_target_monotonic(&self, block: &Block<N>) -> Result<()> {
+        // The genesis block has no previous block.
+        if block.height() == 0 {
+            return Ok(());
+        }
+        // Retrieve the cumulative proof target of the previous block.
+        let previous_cumulative_proof_target = self.ledger.get_header(block.height() - 1)?.cumulative_proof_target();
+        // Ensure the cumulative proof target did not decrease.
+        ensure_monotonic_target(
+            previous_cumulative_proof_target,
+            block.cumulative_proof_target(),
+            block.solutions().is_some(),
+        )
+        .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Returns the epoch number of the latest block.
+    pub fn latest_epoch_number(&self) -> u32 {
+        self.epoch_for_height(self.latest_block_height())
+    }
+
+    /// Returns the epoch number of the given block height.
+    pub fn epoch_for_height(&self, height: u32) -> u32 {
+        height / N::NUM_BLOCKS_PER_EPOCH
+    }
+
+    /// Returns the block heights covered by the given epoch.
+    /// The range is inclusive of the start and exclusive of the end.
+    pub fn epoch_height_range(&self, epoch: u32) -> Range<u32> {
+        let start = epoch.saturating_mul(N::NUM_BLOCKS_PER_EPOCH);
+        start..start.saturating_add(N::NUM_BLOCKS_PER_EPOCH)
+    }
+
+    /// Returns the epoch challenge for the latest epoch, computing it if the epoch has changed.
+    fn latest_epoch_challenge(&self) -> Result<EpochChallenge<N>> {
+        // Retrieve the latest epoch number.
+        let epoch_number = self.latest_epoch_number();
+        // Return the cached epoch challenge, if it is for the latest epoch.
+        if let Some((cached_epoch_number, epoch_challenge)) = &*self.epoch_challenge_cache.read() {
+            if *cached_epoch_number == epoch_number {
+                return Ok(epoch_challenge.clone());
+            }
+        }
+        // Compute the latest epoch challenge.
+        let epoch_challenge = self.ledger.latest_epoch_challenge()?;
+        // Cache the epoch challenge.
+        *self.epoch_challenge_cache.write() = Some((epoch_number, epoch_challenge.clone()));
+        Ok(epoch_challenge)
+    }
+
+    /// Ensures the given block's previous state root matches the state root of the prior block,
+    /// and that the block's transactions (including their finalize operations) match its transactions root.
+    ///
+    /// Note: Only the previous state root and the transactions root are checked. The finalize root also commits
+    /// to the finalize operations of the block's ratifications, which are not stored in the block, and are only
+    /// known by speculating on the block (as in `check_next_block`).
+    pub fn check_state_transition(&self, block: &Block<N>) -> Result<()> {
+        let height = block.height();
+        // Ensure the previous state root matches the state root of the prior block.
+        if let Some(previous_height) = height.checked_sub(1) {
+            let Some(previous_state_root) = self.ledger.get_state_root(previous_height)? else {
+                bail!("Missing the state root for block {previous_height}");
+            };
+            ensure_root_matches("previous state root", height, previous_state_root, block.previous_state_root())?;
+        }
+        // Recompute the transactions root.
+        let transactions_root = block.transactions().to_transactions_root()?;
+        // Ensure the transactions root matches the header.
+        ensure_root_matches("transactions root", height, transactions_root, block.header().transactions_root())
+    }
+
+    /// Returns the lowest block height that is available in storage.
+    pub fn earliest_available_height(&self) -> u32 {
+        find_earliest_height(self.latest_block_height(), |height| self.contains_block_height(height))
+    }
+
+    /// Ensures the given solution was computed for the epoch of the block that will include the given round.
+    pub fn check_solution_epoch(&self, solution: &ProverSolution<N>, round: u64) -> Result<()> {
+        // Determine the height of the block that will include the round.
+        let target_height = match round > self.latest_round() {
+            true => self.latest_block_height().saturating_add(1),
+            false => self.find_first_height_at_or_above_round(round)?,
+        };
+        // Retrieve the epoch number and epoch challenge for the target height.
+        let epoch_number = self.epoch_for_height(target_height);
+        let epoch_challenge = self.ledger.get_epoch_challenge(target_height)?;
+        // Ensure the solution is valid for the epoch challenge.
+        if self.solution_verifier.verify_solution(solution, &epoch_challenge, 0)? {
+            return Ok(());
+        }
+        // Determine if the solution was computed for the previous epoch, to report a stale solution.
+        if let Some(previous_height) = (epoch_number * N::NUM_BLOCKS_PER_EPOCH).checked_sub(1) {
+            let previous_epoch_challenge = self.ledger.get_epoch_challenge(previous_height)?;
+            if self.solution_verifier.verify_solution(solution, &previous_epoch_challenge, 0)? {
+                bail!("Solution '{}' is for the stale epoch {}", fmt_id(solution.commitment()), epoch_number - 1);
+            }
+        }
+        bail!("Solution '{}' is not for epoch {epoch_number} (round {round})", fmt_id(solution.commitment()))
+    }
+
+    /// Returns the fraction of the current committee's total stake held by the given address.
+    pub fn voting_power(&self, address: &Address<N>) -> Result<f64> {
+        Ok(stake_fraction(&self.current_committee()?, [*address]))
+    }
+
+    /// Returns the fraction of the total stake held by the given address, in the committee for the given round.
+    pub fn voting_power_for_round(&self, address: &Address<N>, round: u64) -> Result<f64> {
+        Ok(stake_fraction(&self.get_previous_committee_for_round(round)?, [*address]))
+    }
+
+    /// Returns the heights in the given block range that are absent from storage.
+    /// The range is inclusive of the start and exclusive of the end.
+    pub fn find_missing_heights(&self, heights: Range<u32>) -> Result<Vec<u32>> {
+        // Ensure the block range is valid.
+        self.ensure_valid_block_range(&heights)?;
+        // Return the heights that are absent from storage.
+        Ok(missing_heights(heights, |height| self.contains_block_height(height)))
+    }
+
+    /// Ensures the round of the given block advances past the round of the latest block,
+    /// by no more than is plausible for the size of the current committee.
+    pub fn check_round_advance(&self, block: &Block<N>) -> Result<()> {
+        // Retrieve the current committee.
+        let committee = self.current_committee()?;
+        // Ensure the block advances the round by a plausible amount.
+        ensure_round_advance(self.latest_round(), block.round(), committee.num_members())
+            .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Returns the participation of each validator that was in the committee during the given epoch.
+    /// If the epoch is in progress, the report covers the blocks up to the latest block.
+    pub fn epoch_participation_report(&self, epoch: u32) -> Result<IndexMap<Address<N>, ParticipationStats>> {
+        // Determine the block range of the epoch.
+        let heights = self.epoch_height_range(epoch);
+        if heights.start > self.latest_block_height() {
+            bail!("Epoch {epoch} has not started yet");
+        }
+        let (start_height, end_height) = (heights.start, heights.end.min(self.latest_block_height() + 1));
+
+        // Initialize the report.
+        let mut report = IndexMap::<Address<N>, ParticipationStats>::new();
+        for height in start_height..end_height {
+            // If the Ctrl-C handler registered the signal, then stop building the report.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the participation report for epoch {epoch} - The node is shutting down");
+            }
+            // Retrieve the block.
+            let block = self.ledger.get_block(height)?;
+            // Retrieve the committee for the block round.
+            let committee = self.get_committee_for_round(block.round())?;
+            // Update the rewards of each committee member.
+            for address in committee.members().keys() {
+                let reward = self.validator_reward_share(address, height)?;
+                let stats = report.entry(*address).or_default();
+                stats.rewards_earned = stats.rewards_earned.saturating_add(reward);
+            }
+            // Update the authored rounds and led blocks from the block's subdag.
+            if let Authority::Quorum(subdag) = block.authority() {
+                for certificate in subdag.values().flatten() {
+                    report.entry(certificate.author()).or_default().rounds_authored += 1;
+                }
+                report.entry(subdag.leader_certificate().author()).or_default().blocks_led += 1;
+            }
+        }
+        Ok(report)
+    }
+
+    /// Returns the committed certificate that carried the given transaction, or `None` if the transaction
+    /// is unconfirmed or was not included by a certificate (e.g. in the genesis block).
+    pub fn get_transaction_certificate(&self, transaction_id: N::TransactionID) -> Result<Option<BatchCertificate<N>>> {
+        // Retrieve the hash of the block containing the transaction.
+        let Some(block_hash) = self.ledger.find_block_hash(&transaction_id)? else {
+            return Ok(None);
+        };
+        // Retrieve the block.
+        let block = self.ledger.get_block(self.ledger.get_height(&block_hash)?)?;
+        // Retrieve the subdag of the block.
+        let Authority::Quorum(subdag) = block.authority() else {
+            return Ok(None);
+        };
+        // Find the certificate that carried the transaction.
+        let transmission_id = TransmissionID::Transaction(transaction_id);
+        Ok(subdag
+            .values()
+            .flatten()
+            .find(|certificate| certificate.transmission_ids().contains(&transmission_id))
+            .cloned())
+    }
+
+    /// Ensures the total stake of the given committee is consistent with the stakes of its members.
+    pub fn verify_committee_integrity(&self, committee: &Committee<N>) -> Result<()> {
+        ensure_consistent_stake(committee.members(), committee.total_stake())
+            .map_err(|error| anyhow!("Invalid committee for round {} - {error}", committee.starting_round()))
+    }
+
+    /// Returns the checkpoint hash at the given height, which accumulates the block hashes from genesis
+    /// up to and including the given height. Two nodes with the same checkpoint hash share the same history.
+    pub fn checkpoint_hash(&self, height: u32) -> Result<Field<N>> {
+        // Ensure the height exists in the ledger.
+        if height > self.latest_block_height() {
+            bail!("Block {height} does not exist in the ledger");
+        }
+        // Start from the latest cached checkpoint at or below the height, if it is still on the canonical chain.
+        let cached = self
+            .checkpoint_cache
+            .read()
+            .range(..=height)
+            .next_back()
+            .map(|(cached_height, entry)| (*cached_height, *entry));
+        let (start_height, mut checkpoint_hash) = match cached {
+            Some((cached_height, (block_hash, checkpoint_hash)))
+                if self.ledger.get_hash(cached_height)? == block_hash =>
+            {
+                // Return the cached checkpoint hash, if it is for the height.
+                if cached_height == height {
+                    return Ok(checkpoint_hash);
+                }
+                (cached_height + 1, checkpoint_hash)
+            }
+            _ => (0, Field::zero()),
+        };
+        // Accumulate the block hashes up to the height.
+        for current_height in start_height..=height {
+            // If the Ctrl-C handler registered the signal, then stop the accumulation.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the checkpoint hash for block {height} - The node is shutting down");
+            }
+            // Accumulate the block hash.
+            let block_hash = self.ledger.get_hash(current_height)?;
+            checkpoint_hash = accumulate_checkpoint_hash(checkpoint_hash, block_hash)?;
+            // Cache the checkpoint hash at each interval.
+            if current_height % CHECKPOINT_HASH_INTERVAL == 0 {
+                self.checkpoint_cache.write().insert(current_height, (block_hash, checkpoint_hash));
+            }
+        }
+        Ok(checkpoint_hash)
+    }
+
+    /// Returns the number of confirmed transactions in the given block range that reference the given address
+    /// in the public inputs or outputs of their transitions, including the fee transition (and thus the fee payer).
+    /// The range is inclusive of the start and exclusive of the end.
+    pub fn address_transaction_count(&self, address: &Address<N>, heights: Range<u32>) -> Result<u64> {
+        // Ensure the block range is valid.
+        self.ensure_valid_block_range(&heights)?;
+        // Count the transactions that reference the address.
+        let mut count = 0u64;
+        for height in heights {
+            // If the Ctrl-C handler registered the signal, then stop the count.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the transaction count at block {height} - The node is shutting down");
+            }
+            // Retrieve the transactions of the block.
+            let transactions = self.ledger.get_block(height)?.transactions().clone();
+            // Count the transactions that reference the address.
+            count += transactions
+                .iter()
+                .filter(|transaction| {
+                    transaction.transaction().transitions().any(|transition| transition_references(transition, address))
+                })
+                .count() as u64;
+        }
+        Ok(count)
+    }
+
+    /// Returns the block reward (in microcredits) scheduled by the protocol for the block at the given height,
+    /// recomputed from the starting supply, the height, and the block's solutions and transaction fees,
+    /// independent of the rewards recorded in the block.
+    ///
+    /// The block reward and the puzzle reward are compared against the rewards ratified in the block,
+    /// and a discrepancy between them is returned as an error.
+    pub fn expected_block_reward(&self, height: u32) -> Result<u64> {
+        // Retrieve the block.
+        let block = self.ledger.get_block(height)?;
+        // Compute the block reward and puzzle reward.
+        let (block_reward, puzzle_reward) =
+            self.compute_block_rewards(&block).map_err(|error| anyhow!("Invalid block {height} - {error}"))?;
+        // Ensure the ratified rewards match the expected rewards.
+        ensure_rewards_match(block_reward, puzzle_reward, block.ratifications().iter())
+            .map_err(|error| anyhow!("Reward discrepancy in block {height} - {error}"))?;
+        Ok(block_reward)
+    }
+
+    /// Ensures the given address is the leader elected by the committee for the given (even) round.
+    pub fn check_leader_eligibility(&self, round: u64, leader: &Address<N>) -> Result<()> {
+        // Ensure the round is even, as leaders are only elected in even rounds.
+        if round % 2 != 0 {
+            bail!("Round {round} is odd, and does not have a leader");
+        }
+        // Retrieve the previous committee for the round.
+        let previous_committee = self.get_previous_committee_for_round(round)?;
+        // Compute the leader for the round.
+        let expected_leader = previous_committee.get_leader(round)?;
+        // Ensure the leader matches.
+        if expected_leader != *leader {
+            bail!("Invalid leader for round {round} - expected {expected_leader}, found {leader}");
+        }
+        Ok(())
+    }
+
+    /// Returns the number of transactions and solutions verified per second, over the rolling throughput window.
+    pub fn verification_throughput(&self) -> ThroughputStats {
+        let now = Instant::now();
+        ThroughputStats {
+            tx_per_sec: verifications_per_sec(&self.verified_transactions, now),
+            solution_per_sec: verifications_per_sec(&self.verified_solutions, now),
+        }
+    }
+
+    /// Ensures the author of each certificate in the given subdag is a member of the committee for its round.
+    pub fn verify_subdag_authors(&self, subdag: &Subdag<N>) -> Result<()> {
+        ensure_committee_authors(subdag, |round| self.get_committee_for_round(round))
+    }
+
+    /// Ensures every certificate in the given subdag reaches quorum in the committee for its round,
+    /// as in `verify_certificate_quorum`, loading the committee once per round.
+    pub fn verify_subdag_committees(&self, subdag: &Subdag<N>) -> Result<()> {
+        ensure_subdag_quorums(subdag, |round| self.get_previous_committee_for_round(round))
+    }
+
+    /// Returns the withdrawal address of the given validator from the `credits.aleo/withdraw` mapping,
+    /// or `None` if the validator has not set one (or the network does not support withdrawal addresses).
+    pub fn get_withdrawal_address(&self, validator: &Address<N>) -> Result<Option<Address<N>>> {
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let mapping_name = Identifier::from_str("withdraw")?;
+        // Retrieve the finalize store.
+        let finalize_store = self.ledger.vm().finalize_store();
+        // Ensure the network supports withdrawal addresses.
+        if !finalize_store.get_mapping_names_confirmed(&program_id)?.contains(&mapping_name) {
+            return Ok(None);
+        }
+        // Retrieve the withdrawal address.
+        let key = Plaintext::from(Literal::Address(*validator));
+        match finalize_store.get_value_confirmed(program_id, mapping_name, &key)? {
+            Some(value) => Ok(Some(value_to_address(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Ensures the fee of the given transaction covers the cost of its deployment or execution.
+    pub fn check_fee_sufficient(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Compute the cost of the transaction.
+        let (cost, _) = match transaction {
+            Transaction::Deploy(_, _, deployment, _) => deployment_cost(deployment)?,
+            Transaction::Execute(_, execution, _) => execution_cost(&self.ledger.vm().process().read(), execution)?,
+            Transaction::Fee(..) => bail!("Transaction '{}' is a fee transaction, and has no cost", transaction.id()),
+        };
+        // Ensure the fee covers the cost.
+        ensure_fee_covers_cost(*transaction.fee_amount()?, cost)
+            .map_err(|error| anyhow!("Invalid transaction '{}' - {error}", transaction.id()))
+    }
+
+    /// Writes a snapshot of the confirmed entries of every mapping in the finalize store to the given path.
+    ///
+    /// The finalize store only holds the state as of the latest block, so the given height must be the latest
+    /// block height. This reads every mapping entry of every program, and is expensive on a large store.
+    pub fn export_finalize_snapshot(&self, height: u32, path: &Path) -> Result<()> {
+        // Ensure the height is the latest block height.
+        let block_hash = self.ledger.latest_hash();
+        if height != self.latest_block_height() {
+            bail!("Cannot snapshot the finalize store at block {height} - only the latest block is available");
+        }
+        // Retrieve the program IDs.
+        let program_ids = self.ledger.vm().process().read().program_ids().copied().collect::<Vec<_>>();
+        // Retrieve the finalize store.
+        let finalize_store = self.ledger.vm().finalize_store();
+        // Collect the entries of each mapping.
+        let mut mappings = Vec::new();
+        for program_id in program_ids {
+            for mapping_name in finalize_store.get_mapping_names_confirmed(&program_id)? {
+                // If the Ctrl-C handler registered the signal, then stop the snapshot.
+                if self.shutdown.load(Ordering::Relaxed) {
+                    bail!("Skipping the finalize snapshot at block {height} - The node is shutting down");
+                }
+                let entries = finalize_store.get_mapping_confirmed(&program_id, &mapping_name)?;
+                mappings.push((program_id, mapping_name, entries));
+            }
+        }
+        // Ensure the ledger did not advance during the snapshot.
+        if self.ledger.latest_hash() != block_hash {
+            bail!("Cannot snapshot the finalize store at block {height} - the ledger advanced during the snapshot");
+        }
+        // Write the snapshot.
+        let snapshot = FinalizeSnapshot { height, block_hash, mappings };
+        std::fs::write(path, snapshot.to_bytes_le()?)?;
+        Ok(())
+    }
+
+    /// Reads a snapshot of the finalize store from the given path, ensuring it was taken on this ledger's chain.
+    pub fn import_finalize_snapshot(&self, path: &Path) -> Result<FinalizeSnapshot<N>> {
+        // Read the snapshot.
+        let snapshot = FinalizeSnapshot::<N>::from_bytes_le(&std::fs::read(path)?)?;
+        // Ensure the snapshot block is in the ledger.
+        if self.ledger.get_hash(snapshot.height)? != snapshot.block_hash {
+            bail!("The finalize snapshot at block {} is not from this ledger", snapshot.height);
+        }
+        Ok(snapshot)
+    }
+
+    /// Ensures the given block does not contain the same transaction more than once.
+    pub fn check_unique_transactions(&self, block: &Block<N>) -> Result<()> {
+        ensure_unique_transaction_ids::<N>(block.transaction_ids())
+            .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Returns the projected committee for the given round.
+    ///
+    /// This is a projection, not a guarantee: bonds and unbonds update the committee when they are finalized,
+    /// and claiming an unbond does not change the committee again, so the committee for a future round is
+    /// projected to be the current committee. It may still change if further bonds or unbonds are finalized
+    /// before the round. For a past round, the committee is returned.
+    pub fn projected_committee_for_round(&self, round: u64) -> Result<Committee<N>> {
+        match round > self.latest_round() {
+            true => self.current_committee(),
+            false => self.get_committee_for_round(round),
+        }
+    }
+
+    /// Ensures the given solution was computed against the epoch challenge for the next block,
+    /// catching solutions computed against a stale or wrong epoch challenge.
+    pub fn verify_solution_epoch_challenge(&self, solution: &ProverSolution<N>) -> Result<()> {
+        self.check_solution_epoch(solution, self.latest_round().saturating_add(1))
+    }
+
+    /// Returns the smallest number of certificates, from the highest-staked validators, that reach
+    /// the quorum threshold in the given round.
+    pub fn min_certificates_for_quorum(&self, round: u64) -> Result<usize> {
+        // Retrieve the committee for the round.
+        let committee = self.get_previous_committee_for_round(round)?;
+        // Compute the smallest number of validators that reach the quorum threshold.
+        min_members_for_quorum(&committee).map_err(|error| anyhow!("Invalid round {round} - {error}"))
+    }
+
+    /// Returns the transmission IDs in the committed certificate(s) authored by the given address in the given round.
+    pub fn validator_transmissions_in_round(&self, author: &Address<N>, round: u64) -> Result<Vec<TransmissionID<N>>> {
+        Ok(author_transmission_ids(&self.get_certificates_for_round(round)?, author))
+    }
+
+    /// Ensures the genesis block in the ledger is well-formed.
+    pub fn verify_genesis_block(&self) -> Result<()> {
+        // Retrieve the genesis block.
+        let block = self.ledger.get_block(0)?;
+        // Ensure the genesis block is well-formed.
+        let is_beacon = matches!(block.authority(), Authority::Beacon(..));
+        ensure_genesis_parts(block.height(), block.round(), is_beacon, block.ratifications().iter())
+    }
+
+    /// Returns the IDs of the certificates committed in the block at the given height.
+    /// If the block is not a quorum block, then an empty list is returned.
+    pub fn get_block_certificate_ids(&self, height: u32) -> Result<Vec<Field<N>>> {
+        match self.ledger.get_block(height)?.authority() {
+            Authority::Quorum(subdag) => Ok(subdag.values().flatten().map(|certificate| certificate.id()).collect()),
+            Authority::Beacon(..) => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the fraction of the current committee's total stake held by the smallest set of validators
+    /// that together reach the quorum threshold.
+    pub fn quorum_concentration(&self) -> Result<f64> {
+        quorum_concentration_of(&self.current_committee()?)
+    }
+
+    /// Returns the Nakamoto coefficient of the current committee, which is the smallest number of validators
+    /// that together reach the quorum threshold.
+    pub fn nakamoto_coefficient(&self) -> Result<usize> {
+        min_members_for_quorum(&self.current_committee()?)
+    }
+
+    /// Returns the fraction of the current committee's total stake held by its largest validator.
+    pub fn largest_validator_share(&self) -> Result<f64> {
+        Ok(largest_share_of(&self.current_committee()?))
+    }
+
+    /// Ensures the given transaction, if it is a deployment, does not deploy a program that is already deployed.
+    pub fn check_deployment_unique(&self, transaction: &Transaction<N>) -> Result<()> {
+        match transaction {
+            Transaction::Deploy(_, _, deployment, _) => {
+                ensure_program_not_deployed(deployment.program_id(), |program_id| {
+                    self.ledger.vm().process().read().contains_program(program_id)
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the number of blocks into the current epoch, and the length of the epoch in blocks.
+    pub fn epoch_progress(&self) -> Result<(u32, u32)> {
+        self.epoch_progress_at(self.latest_block_height())
+    }
+
+    /// Returns the number of blocks into the epoch of the given height, and the length of the epoch in blocks.
+    fn epoch_progress_at(&self, height: u32) -> Result<(u32, u32)> {
+        let heights = self.epoch_height_range(self.epoch_for_height(height));
+        let epoch_length = u32::try_from(heights.len())?;
+        Ok((height - heights.start, epoch_length))
+    }
+
+    /// Ensures the program and function called by each transition in the given block are deployed and loadable,
+    /// including their finalize logic.
+    pub fn check_block_programs_available(&self, block: &Block<N>) -> Result<()> {
+        // Retrieve the process.
+        let process = self.ledger.vm().process();
+        let process = process.read();
+        // Ensure each function is available.
+        ensure_functions_available(block.transitions(), |program_id, function_name| {
+            process.get_program(program_id).and_then(|program| program.get_function(function_name)).is_ok()
+        })
+        .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Returns a digest of the hashes of the last `window` blocks, for peers to cheaply detect divergence.
+    pub fn rolling_digest(&self, window: u32) -> Result<Field<N>> {
+        // Ensure the window is non-empty.
+        if window == 0 {
+            bail!("Cannot compute a rolling digest over an empty window");
+        }
+        // Determine the block range of the window.
+        let end_height = self.latest_block_height().saturating_add(1);
+        let heights = end_height.saturating_sub(window)..end_height;
+        // Accumulate the block hashes into the digest.
+        self.get_block_hashes(heights)?
+            .into_iter()
+            .try_fold(Field::zero(), |digest, block_hash| accumulate_checkpoint_hash(digest, block_hash))
+    }
+
+    /// Ensures the ID of the given certificate is the batch ID recomputed from the fields of its batch header.
+    pub fn verify_certificate_id(&self, certificate: &BatchCertificate<N>) -> Result<()> {
+        ensure_certificate_id(certificate.id(), certificate.batch_header())
+    }
+
+    /// Ensures the round of the given certificate immediately follows the rounds of its previous certificates
+    /// that are in the ledger. Previous certificates that are not in the ledger are not checked.
+    pub fn verify_certificate_round_consistency(&self, certificate: &BatchCertificate<N>) -> Result<()> {
+        // Retrieve the previous certificates that are in the ledger.
+        let mut previous_certificates = Vec::new();
+        for previous_certificate_id in certificate.previous_certificate_ids() {
+            if let Some(previous_certificate) = self.ledger.get_batch_certificate(previous_certificate_id)? {
+                previous_certificates.push(previous_certificate);
+            }
+        }
+        // Ensure the certificate round follows the previous round.
+        ensure_certificate_round(certificate, &previous_certificates)
+    }
+
+    /// Returns the hash of the block preceding the given height, without retrieving the full block.
+    pub fn get_previous_block_hash(&self, height: u32) -> Result<N::BlockHash> {
+        // Ensure the block exists in the ledger.
+        if !self.contains_block_height(height) {
+            bail!("Block {height} does not exist in the ledger");
+        }
+        match self.ledger.vm().block_store().get_previous_block_hash(height)? {
+            Some(previous_hash) => Ok(previous_hash),
+            None => bail!("Missing the previous block hash for block {height}"),
+        }
+    }
+
+    /// Returns the number of transactions in the block at the given height.
+    pub fn get_block_transaction_count(&self, height: u32) -> Result<usize> {
+        Ok(self.ledger.get_transactions(height)?.len())
+    }
+
+    /// Returns the number of solutions accepted in the block at the given height.
+    pub fn get_block_solution_count(&self, height: u32) -> Result<usize> {
+        Ok(self.ledger.get_solutions(height)?.map_or(0, |solutions| solutions.len()))
+    }
+
+    /// Returns the number of transactions per second over the last `blocks` blocks,
+    /// or zero if the blocks do not span any time (e.g. a single block).
+    pub fn recent_tps(&self, blocks: u32) -> Result<f64> {
+        // Determine the block range.
+        let end_height = self.latest_block_height().saturating_add(1);
+        let heights = end_height.saturating_sub(blocks)..end_height;
+        // Ensure the block range is valid.
+        self.ensure_valid_block_range(&heights)?;
+        if heights.is_empty() {
+            bail!("Cannot compute the transactions per second over zero blocks");
+        }
+        // Sum the transactions in the blocks.
+        let mut num_transactions = 0u64;
+        for height in heights.clone() {
+            num_transactions += self.get_block_transaction_count(height)? as u64;
+        }
+        // Compute the time span of the blocks.
+        let time_span = self.inter_block_time(heights.start, heights.end - 1)?;
+        Ok(transactions_per_second(num_transactions, time_span))
+    }
+
+    /// Returns `true` if the given solution is valid for the given epoch challenge and proof target,
+    /// under the given coinbase verifying key rather than the configured solution verifier.
+    pub fn verify_solution_with_key(
+        &self,
+        solution: &ProverSolution<N>,
+        key: &CoinbaseVerifyingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<bool> {
+        key.verify_solution(solution, epoch_challenge, proof_target)
+    }
+
+    /// Returns the author of each certificate committed in the block at the given height, along with the offset
+    /// (in seconds) of the certificate's timestamp from the block's timestamp.
+    /// If the block is not a quorum block, then an empty list is returned.
+    pub fn certificate_timestamp_skew(&self, height: u32) -> Result<Vec<(Address<N>, i64)>> {
+        // Retrieve the block.
+        let block = self.ledger.get_block(height)?;
+        // Compute the timestamp skew of each certificate.
+        match block.authority() {
+            Authority::Quorum(subdag) => Ok(timestamp_skews(subdag.values().flatten(), block.timestamp())),
+            Authority::Beacon(..) => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the fraction of the given rounds in which the given address authored a committed certificate.
+    /// The range is inclusive of the start and exclusive of the end.
+    pub fn validator_uptime(&self, address: &Address<N>, rounds: Range<u64>) -> Result<f64> {
+        // Ensure the round range is bounded.
+        if rounds.end.saturating_sub(rounds.start) > MAX_ROUND_RANGE {
+            bail!("Cannot query more than {MAX_ROUND_RANGE} rounds at once (requested {rounds:?})");
+        }
+        uptime_over_rounds(rounds, |round| {
+            // If the Ctrl-C handler registered the signal, then stop the scan.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the uptime scan at round {round} - The node is shutting down");
+            }
+            // Determine if the address authored a committed certificate in the round.
+            Ok(self.get_certificates_for_round(round)?.iter().any(|certificate| certificate.author() == *address))
+        })
+    }
+
+    /// Ensures the block reward and puzzle reward ratified in the given block match the rewards recomputed
+    /// from the block's solutions and transactions.
+    pub fn check_ratification_consistency(&self, block: &Block<N>) -> Result<()> {
+        // Compute the expected rewards.
+        let (block_reward, puzzle_reward) =
+            self.compute_block_rewards(block).map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))?;
+        // Ensure the ratified rewards match the expected rewards.
+        ensure_rewards_match(block_reward, puzzle_reward, block.ratifications().iter())
+            .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Returns the block reward and puzzle reward (in microcredits) for the given block, recomputed from its height,
+    /// solutions, and transaction fees, as the protocol computes them when the block is produced.
+    fn compute_block_rewards(&self, block: &Block<N>) -> Result<(u64, u64)> {
+        // Retrieve the header of the previous block.
+        let Some(previous_height) = block.height().checked_sub(1) else {
+            bail!("The genesis block does not ratify rewards");
+        };
+        let previous_header = self.ledger.get_header(previous_height)?;
+        // Compute the combined proof target of the solutions.
+        let mut combined_proof_target = 0u128;
+        for solution in block.solutions().into_iter().flat_map(|solutions| solutions.values()) {
+            combined_proof_target = combined_proof_target.saturating_add(solution.to_target()? as u128);
+        }
+        // Compute the transaction fees.
+        let mut transaction_fees = 0u64;
+        for transaction in block.transactions().iter() {
+            transaction_fees = transaction_fees.saturating_add(*transaction.transaction().fee_amount()?);
+        }
+        // Compute the coinbase reward.
+        let coinbase_reward = coinbase_reward(
+            block.height(),
+            N::STARTING_SUPPLY,
+            N::ANCHOR_HEIGHT,
+            N::BLOCK_TIME,
+            combined_proof_target,
+            u64::try_from(previous_header.cumulative_proof_target())?,
+            previous_header.coinbase_target(),
+        )?;
+        // Compute the block reward and puzzle reward.
+        let block_reward = block_reward(N::STARTING_SUPPLY, N::BLOCK_TIME, coinbase_reward, transaction_fees);
+        Ok((block_reward, puzzle_reward(coinbase_reward)))
+    }
+
+    /// Returns up to `page_size` of the confirmed transactions that reference the given address (as in
+    /// `address_transaction_count`), scanning backward from below `before_height`, along with the cursor
+    /// to pass as `before_height` for the next page, or `None` once the genesis block is reached.
+    pub fn address_transactions_paged(
+        &self,
+        address: &Address<N>,
+        before_height: u32,
+        page_size: u32,
+    ) -> Result<(Vec<(u32, N::TransactionID)>, Option<u32>)> {
+        // Ensure the page size is non-zero.
+        if page_size == 0 {
+            bail!("Cannot retrieve an empty page of transactions");
+        }
+        let before_height = before_height.min(self.latest_block_height().saturating_add(1));
+        page_backward(before_height, page_size, |height| {
+            // If the Ctrl-C handler registered the signal, then stop the scan.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the transaction scan at block {height} - The node is shutting down");
+            }
+            // Retrieve the transactions that reference the address.
+            Ok(self
+                .ledger
+                .get_transactions(height)?
+                .iter()
+                .filter(|transaction| {
+                    transaction.transaction().transitions().any(|transition| transition_references(transition, address))
+                })
+                .map(|transaction| transaction.id())
+                .collect())
+        })
+    }
+
+    /// Returns the height of the first block whose transactions or ratified balances reference the given address,
+    /// or `None` if the address was never seen.
+    ///
+    /// This scans the chain forward from genesis, and retrieves every block up to the first reference,
+    /// so the cost of a query for an address that was never seen is proportional to the length of the chain.
+    pub fn address_first_seen(&self, address: &Address<N>) -> Result<Option<u32>> {
+        first_height_where(0..self.latest_block_height().saturating_add(1), |height| {
+            // If the Ctrl-C handler registered the signal, then stop the scan.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the address scan at block {height} - The node is shutting down");
+            }
+            // Retrieve the block.
+            let block = self.ledger.get_block(height)?;
+            // Determine if the ratifications or transactions reference the address.
+            Ok(block.ratifications().iter().any(|ratification| ratification_references(ratification, address))
+                || block.transactions().iter().any(|transaction| {
+                    transaction.transaction().transitions().any(|transition| transition_references(transition, address))
+                }))
+        })
+    }
+
+    /// Returns the bonding events involving the given address in the given block range, in the order they were
+    /// confirmed. The range is inclusive of the start and exclusive of the end.
+    pub fn address_bonding_events(&self, address: &Address<N>, heights: Range<u32>) -> Result<Vec<BondingEvent<N>>> {
+        // Ensure the block range is valid.
+        self.ensure_valid_block_range(&heights)?;
+        // Collect the bonding events that involve the address.
+        let mut events = Vec::new();
+        for height in heights {
+            // If the Ctrl-C handler registered the signal, then stop the scan.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the bonding scan at block {height} - The node is shutting down");
+            }
+            for transaction in self.ledger.get_transactions(height)?.iter() {
+                for transition in transaction.transaction().transitions() {
+                    // Retrieve the future of the transition.
+                    let Some(Output::Future(_, Some(future))) = transition.outputs().last() else {
+                        continue;
+                    };
+                    // Ensure the future involves the address.
+                    if !future_references(future, address) {
+                        continue;
+                    }
+                    if let Some((kind, amount)) = bonding_event_of(future) {
+                        events.push(BondingEvent { height, transaction_id: transaction.id(), kind, amount });
+                    }
+                }
+            }
+        }
+        Ok(events)
+    }
+
+    /// Ensures the anchor certificate of the given subdag is linked, through the certificates of the subdag,
+    /// to the anchor certificate of the latest block. If the latest block is not a quorum block, then there
+    /// is no prior anchor to link to.
+    pub fn verify_anchor_path(&self, subdag: &Subdag<N>) -> Result<()> {
+        // Retrieve the anchor certificate of the latest block.
+        let Authority::Quorum(latest_subdag) = self.latest_block().authority().clone() else {
+            return Ok(());
+        };
+        // Ensure the anchor is linked to the prior anchor.
+        ensure_linked(subdag.leader_certificate(), subdag, latest_subdag.leader_certificate().id())
+    }
+
+    /// Ensures the given subdag does not re-commit stale certificates, i.e. that every certificate in the subdag
+    /// is within the GC depth of the subdag's anchor round, and is not already committed in the ledger.
+    ///
+    /// Note: The subdag may contain certificates at or below the anchor round of the latest block, as a certificate
+    /// that was not committed by a prior anchor may still be committed by a later anchor, up to the GC depth.
+    pub fn check_subdag_freshness(&self, subdag: &Subdag<N>) -> Result<()> {
+        // Ensure the certificates are within the GC depth of the anchor round.
+        ensure_rounds_within_gc(subdag, subdag.anchor_round())?;
+        // Ensure the certificates are not already committed.
+        for certificate in subdag.values().flatten() {
+            if self.contains_certificate(&certificate.id())? {
+                bail!(
+                    "Subdag contains the round {} certificate '{}', which is already committed",
+                    certificate.round(),
+                    fmt_id(certificate.id())
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the amount (in microcredits) the given address is currently unbonding,
+    /// from the `credits.aleo/unbonding` mapping.
+    pub fn get_unbonding_amount(&self, address: &Address<N>) -> Result<u64> {
+        self.get_credits_amount("unbonding", address)
+    }
+
+    /// Returns the amount (in microcredits) bonded by the given address, from its entry in the
+    /// `credits.aleo/bonded` mapping. Returns `0` if the address has not bonded.
+    pub fn get_bonded_amount(&self, address: &Address<N>) -> Result<u64> {
+        self.get_credits_amount("bonded", address)
+    }
+
+    /// Returns the `microcredits` of the given address's entry in the given `credits.aleo` mapping,
+    /// or `0` if the address does not have an entry.
+    fn get_credits_amount(&self, mapping: &str, address: &Address<N>) -> Result<u64> {
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let mapping_name = Identifier::from_str(mapping)?;
+        // Retrieve the entry of the address.
+        let key = Plaintext::from(Literal::Address(*address));
+        match self.ledger.vm().finalize_store().get_value_confirmed(program_id, mapping_name, &key)? {
+            Some(value) => struct_member_to_u64(&value, "microcredits"),
+            None => Ok(0),
+        }
+    }
+
+    /// Returns the stake of the given address that counts towards quorum in the current committee.
+    ///
+    /// The protocol does not count unbonding stake towards quorum: `credits.aleo/unbond_public` removes the
+    /// unbonded amount from the committee when it is finalized, and moves it to the `unbonding` mapping until
+    /// it can be claimed. As such, the committee stake is the bonded stake, net of any amount being unbonded.
+    pub fn effective_stake(&self, address: &Address<N>) -> Result<u64> {
+        Ok(self.current_committee()?.get_stake(*address))
+    }
+
+    /// Ensures the mapping keys that the given transaction's finalize logic reads with `get` exist.
+    ///
+    /// This is a heuristic pre-filter, and is not a substitute for finalizing the transaction: it only considers
+    /// `get` commands whose key is a finalize input, and that precede the first branch or `contains` guard (as the
+    /// commands after it may be skipped). It does not account for keys that are inserted or removed by the finalize
+    /// logic itself, or by other transactions finalized before it.
+    pub fn check_transaction_state_refs(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Collect the mapping reads.
+        let mut reads = Vec::new();
+        let process = self.ledger.vm().process();
+        let process = process.read();
+        for transition in transaction.transitions() {
+            // Retrieve the future of the transition, if it has finalize logic.
+            let Some(Output::Future(_, Some(future))) = transition.outputs().last() else {
+                continue;
+            };
+            // Retrieve the finalize logic of the function.
+            let function = process.get_program(transition.program_id())?.get_function(transition.function_name())?;
+            let Some(finalize) = function.finalize_logic() else {
+                continue;
+            };
+            // Collect the unconditional mapping reads whose key is a finalize input.
+            for command in unconditional_commands(finalize.commands()) {
+                let Command::Get(get) = command else {
+                    continue;
+                };
+                let Operand::Register(Register::Locator(index)) = get.key() else {
+                    continue;
+                };
+                let Some(Argument::Plaintext(key)) = future.arguments().get(*index as usize) else {
+                    continue;
+                };
+                let (program_id, mapping_name) = match get.mapping() {
+                    CallOperator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+                    CallOperator::Resource(mapping_name) => (*transition.program_id(), *mapping_name),
+                };
+                reads.push((program_id, mapping_name, key.clone()));
+            }
+        }
+        // Ensure the mapping keys exist.
+        let finalize_store = self.ledger.vm().finalize_store();
+        ensure_mapping_keys_exist(&reads, |program_id, mapping_name, key| {
+            finalize_store.contains_key_confirmed(program_id, mapping_name, key)
+        })
+        .map_err(|error| anyhow!("Invalid transaction '{}' - {error}", transaction.id()))
+    }
+
+    /// Ensures the solutions in the given block were not already committed, to reject replayed solutions.
+    ///
+    /// Note: Solutions from prior epochs fail to verify against the current epoch challenge,
+    /// so checking the committed solutions covers the recent blocks of the current epoch.
+    pub fn check_solution_uniqueness(&self, block: &Block<N>) -> Result<()> {
+        // Retrieve the puzzle commitments of the block.
+        let Some(solutions) = block.solutions() else {
+            return Ok(());
+        };
+        // Ensure the solutions are unique.
+        ensure_unique_solutions(solutions.keys(), |commitment| self.ledger.contains_puzzle_commitment(commitment))
+            .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Returns the given field of the struct value stored under the given key in the given mapping,
+    /// or `None` if the key is not in the mapping or the value does not have the field.
+    pub fn get_mapping_struct_field(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        field: &Identifier<N>,
+    ) -> Result<Option<Value<N>>> {
+        // Retrieve the mapping value.
+        match self.ledger.vm().finalize_store().get_value_confirmed(*program_id, *mapping_name, key)? {
+            // Extract the field.
+            Some(value) => Ok(struct_member(&value, field)),
+            None => Ok(None),
+        }
+    }
+
+    /// Ensures every transmission in the given block is in the given `seen` set, or was confirmed in an earlier block.
+    /// Otherwise, returns an error with the first unseen transmission ID.
+    ///
+    /// This is used to audit that a committed block did not introduce transmissions that this node never validated.
+    pub fn check_block_transmissions_known(&self, block: &Block<N>, seen: &HashSet<TransmissionID<N>>) -> Result<()> {
+        // Retrieve the transmission IDs of the block.
+        let solution_ids = block.solutions().into_iter().flat_map(|solutions| solutions.keys().copied());
+        let transmission_ids = solution_ids
+            .map(TransmissionID::Solution)
+            .chain(block.transaction_ids().copied().map(TransmissionID::Transaction));
+        // Ensure each transmission was seen, or confirmed before the block.
+        ensure_transmissions_known(transmission_ids, |transmission_id| {
+            if seen.contains(transmission_id) {
+                return Ok(true);
+            }
+            let height = match transmission_id {
+                TransmissionID::Ratification => None,
+                TransmissionID::Solution(commitment) => {
+                    self.ledger.vm().block_store().find_block_height_from_puzzle_commitment(commitment)?
+                }
+                TransmissionID::Transaction(transaction_id) => match self.ledger.find_block_hash(transaction_id)? {
+                    Some(block_hash) => Some(self.ledger.get_height(&block_hash)?),
+                    None => None,
+                },
+            };
+            Ok(height.map_or(false, |height| height < block.height()))
+        })
+        .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Returns the elapsed time (in seconds) from the first block of the current epoch to the latest block.
+    pub fn time_since_epoch_start(&self) -> Result<i64> {
+        self.time_since_epoch_start_at(self.latest_block_height())
+    }
+
+    /// Returns the elapsed time (in seconds) from the first block of the epoch of the given height to the given height.
+    fn time_since_epoch_start_at(&self, height: u32) -> Result<i64> {
+        let heights = self.epoch_height_range(self.epoch_for_height(height));
+        self.inter_block_time(heights.start, height)
+    }
+
+    /// Ensures the caller of the given `credits.aleo/bond_public` transaction has a total bonded amount of at least
+    /// the minimum stake as of the latest block, which depends on whether the caller bonds to itself as a validator,
+    /// or delegates to another validator. As such, a bond that tops up an existing bond only needs to meet the
+    /// minimum in total.
+    ///
+    /// Note: The total is computed from the `credits.aleo/bonded` mapping as of the latest block, which includes the
+    /// bonded amount of the transaction once it is committed. The finalize store does not hold the state as of the
+    /// block that committed the bond, so a bond that met the minimum and was later partially unbonded may fail.
+    pub fn verify_bond_meets_minimum_at_latest(&self, transaction: &Transaction<N>) -> Result<()> {
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let function_name = Identifier::from_str("bond_public")?;
+        // Retrieve the bond transition.
+        let Some(transition) = transaction
+            .transitions()
+            .find(|transition| *transition.program_id() == program_id && *transition.function_name() == function_name)
+        else {
+            bail!("Transaction '{}' is not a bond transaction", transaction.id());
+        };
+        // Retrieve the caller, validator, and amount from the finalize arguments.
+        let arguments = match transition.outputs().last() {
+            Some(Output::Future(_, Some(future))) => future.arguments(),
+            _ => bail!("Transaction '{}' is missing the bond arguments", transaction.id()),
+        };
+        let [caller, validator, amount] = arguments else {
+            bail!("Transaction '{}' has malformed bond arguments", transaction.id());
+        };
+        let to_value = |argument: &Argument<N>| match argument {
+            Argument::Plaintext(plaintext) => Ok(Value::Plaintext(plaintext.clone())),
+            Argument::Future(..) => bail!("Expected a plaintext bond argument, found '{argument}'"),
+        };
+        let caller = value_to_address(&to_value(caller)?)?;
+        let validator = value_to_address(&to_value(validator)?)?;
+        let amount = value_to_u64(&to_value(amount)?)?;
+        // Compute the total bonded amount of the caller, including the bond if it is not yet committed.
+        let bonded = self.get_bonded_amount(&caller)?;
+        let total = match self.ledger.contains_transaction_id(&transaction.id())? {
+            true => bonded,
+            false => bonded.saturating_add(amount),
+        };
+        // Ensure the total bonded amount meets the minimum stake.
+        ensure_bond_minimum(&caller, &validator, total)
+            .map_err(|error| anyhow!("Invalid transaction '{}' - {error}", transaction.id()))
+    }
+
+    /// Returns each mapping key set by the finalize operations of the block at the given height, along with its value.
+    ///
+    /// The finalize store only holds the state as of the latest block, so the given height must be the latest
+    /// block height. Finalize operations only commit to the hashes of keys, so keys that the block removed
+    /// cannot be recovered, and are not returned.
+    #[allow(clippy::type_complexity)]
+    pub fn get_block_mapping_updates(
+        &self,
+        height: u32,
+    ) -> Result<Vec<(ProgramID<N>, Identifier<N>, Plaintext<N>, Value<N>)>> {
+        // Ensure the height is the latest block height.
+        if height != self.latest_block_height() {
+            bail!("Cannot retrieve the mapping updates of block {height} - only the latest block is available");
+        }
+        // Retrieve the finalize operations of the block.
+        let transactions = self.ledger.get_block(height)?.transactions().clone();
+        let operations =
+            transactions.iter().flat_map(|transaction| transaction.finalize_operations()).collect::<Vec<_>>();
+        // Collect the entries of each mapping the operations touched.
+        let mapping_ids = operations
+            .iter()
+            .filter_map(|operation| match operation {
+                FinalizeOperation::InsertKeyValue(mapping_id, ..)
+                | FinalizeOperation::UpdateKeyValue(mapping_id, ..) => Some(*mapping_id),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+        let program_ids = self.ledger.vm().process().read().program_ids().copied().collect::<Vec<_>>();
+        let finalize_store = self.ledger.vm().finalize_store();
+        let mut mappings = Vec::new();
+        for program_id in program_ids {
+            for mapping_name in finalize_store.get_mapping_names_confirmed(&program_id)? {
+                // If the Ctrl-C handler registered the signal, then stop the retrieval.
+                if self.shutdown.load(Ordering::Relaxed) {
+                    bail!("Skipping the mapping updates of block {height} - The node is shutting down");
+                }
+                if mapping_ids.contains(&to_mapping_id(&program_id, &mapping_name)?) {
+                    let entries = finalize_store.get_mapping_confirmed(&program_id, &mapping_name)?;
+                    mappings.push((program_id, mapping_name, entries));
+                }
+            }
+        }
+        // Resolve the keys that were set.
+        resolve_mapping_updates(operations, &mappings)
+    }
+
+    /// Ensures the prover of each solution in the given block is in the given prover registry,
+    /// for permissioned networks. If the registry is empty, then any prover is authorized.
+    pub fn check_block_provers_authorized(&self, block: &Block<N>, registry: &HashSet<Address<N>>) -> Result<()> {
+        let solutions = block.solutions().into_iter().flat_map(|solutions| solutions.values());
+        ensure_authorized_provers(solutions, registry)
+            .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Returns the round at which the members and stakes of the current committee first took effect.
+    ///
+    /// The starting round of the committee advances with every block, even if its members do not change,
+    /// so this scans backward through the committees of the preceding blocks to find the last change.
+    pub fn current_committee_activation_round(&self) -> Result<u64> {
+        let history = (0..=self.latest_block_height()).rev().map(|height| {
+            // If the Ctrl-C handler registered the signal, then stop the scan.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the committee scan at block {height} - The node is shutting down");
+            }
+            // Retrieve the committee for the block round.
+            self.get_committee_for_round(self.ledger.get_header(height)?.round())
+        });
+        committee_activation_round(&self.current_committee()?, history)
+    }
+
+    /// Returns the ID of each transaction in the block at the given height that was rejected during finalize,
+    /// along with the reason. Note: The returned IDs are those of the original transactions, and not those
+    /// of the fee transactions that were confirmed in their place.
+    pub fn get_rejected_transactions(&self, height: u32) -> Result<Vec<(N::TransactionID, String)>> {
+        rejected_transactions(self.ledger.get_transactions(height)?.iter())
+    }
+
+    /// Ensures the leader reward of the block at the given height was credited to the elected leader.
+    ///
+    /// The block reward is ratified as a single amount, and is credited to the stakers in proportion to their stake.
+    /// As such, the leader's reward is its share of the block reward, which is credited to the stake bonded to it.
+    /// This ensures the anchor was authored by the elected leader, and that the leader's stake in the committee
+    /// increased by its share of the ratified block reward, excluding the stake bonded to the leader and unbonded
+    /// by the leader in the block. Note: Unbonds by the delegators of the leader in the block are not excluded.
+    pub fn verify_leader_reward(&self, height: u32) -> Result<()> {
+        // Retrieve the block.
+        let block = self.ledger.get_block(height)?;
+        // Retrieve the anchor certificate.
+        let Authority::Quorum(subdag) = block.authority() else {
+            bail!("Block {height} is not a quorum block, and does not have a leader");
+        };
+        let anchor = subdag.leader_certificate();
+        // Ensure the anchor was authored by the elected leader.
+        self.check_leader_eligibility(anchor.round(), &anchor.author())
+            .map_err(|error| anyhow!("Misattributed leader reward in block {height} - {error}"))?;
+        // Retrieve the ratified block reward.
+        let block_reward = block
+            .ratifications()
+            .iter()
+            .find_map(|ratify| match ratify {
+                Ratify::BlockReward(amount) => Some(*amount),
+                _ => None,
+            })
+            .ok_or_else(|| anyhow!("Block {height} does not ratify a block reward"))?;
+        // Retrieve the stake bonded to the leader, and unbonded by the leader, in the block.
+        let (bonded, unbonded) = validator_stake_operations(block.transactions().iter(), &anchor.author())?;
+        // Compute the expected leader reward, from the committee before the block, with the leader's stake
+        // operations applied, as the rewards are credited after the transactions are finalized.
+        let previous_committee = self.get_previous_committee_for_round(block.round())?;
+        let previous_stake = previous_committee.get_stake(anchor.author());
+        let staked = previous_stake.saturating_add(bonded).saturating_sub(unbonded);
+        let total_stake = previous_committee.total_stake().saturating_add(bonded).saturating_sub(unbonded);
+        let expected_reward = proportional_share(block_reward, staked, total_stake);
+        // Compute the credited leader reward, from the committee after the block.
+        let stake = self.get_committee_for_round(block.round())?.get_stake(anchor.author());
+        let credited_reward = stake.saturating_sub(staked);
+        // Ensure the leader was credited the expected reward.
+        let num_stakers = self.num_stakers_at_height(height)?;
+        ensure_leader_reward(expected_reward, credited_reward, num_stakers)
+            .map_err(|error| anyhow!("Misattributed leader reward in block {height} - {error}"))
+    }
+
+    /// Returns the number of stakers in the `credits.aleo/bonded` mapping as of the block at the given height,
+    /// by reverting the stakers that the transactions of the later blocks inserted into or removed from the mapping.
+    pub fn num_stakers_at_height(&self, height: u32) -> Result<u64> {
+        // Ensure the block exists.
+        let latest_height = self.latest_block_height();
+        if height > latest_height {
+            bail!("Block {height} does not exist in the ledger");
+        }
+        // Start from the stakers as of the latest block.
+        let mut num_stakers = i64::try_from(self.bonded_addresses()?.len())?;
+        for later_height in height + 1..=latest_height {
+            // If the Ctrl-C handler registered the signal, then stop the count.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the staker count at block {height} - The node is shutting down");
+            }
+            // Revert the stakers inserted or removed by the block.
+            let transactions = self.ledger.get_transactions(later_height)?;
+            num_stakers -=
+                staker_count_delta(transactions.iter().flat_map(|transaction| transaction.finalize_operations()))?;
+        }
+        Ok(u64::try_from(num_stakers)?)
+    }
+
+    /// Ensures the number of solutions accepted in the given block does not exceed the per-block maximum.
+    pub fn check_solution_count_cap(&self, block: &Block<N>) -> Result<()> {
+        let num_solutions = block.solutions().map_or(0, |solutions| solutions.len());
+        ensure_solution_count(num_solutions, N::MAX_SOLUTIONS)
+            .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Returns the round that the next committed block is expected to target.
+    ///
+    /// A leader is elected for every even round, regardless of the size of the committee, so the next block
+    /// is expected at the first anchor round after the latest block round. If `latest_round` falls behind this
+    /// round as rounds advance, the chain is not keeping pace.
+    pub fn expected_next_round(&self) -> Result<u64> {
+        // Ensure the committee for the next anchor round can elect a leader.
+        let round = next_anchor_round(self.latest_round());
+        self.get_previous_committee_for_round(round)?.get_leader(round)?;
+        Ok(round)
+    }
+
+    /// Returns the commission rate (as a percentage) of the given validator, from its entry in the
+    /// `credits.aleo/committee` mapping. Returns `None` if the validator is not in the committee, or if the
+    /// network does not support commissions.
+    pub fn get_validator_commission(&self, validator: &Address<N>) -> Result<Option<u8>> {
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let mapping_name = Identifier::from_str("committee")?;
+        let field = Identifier::from_str("commission")?;
+        // Retrieve the commission of the validator.
+        let key = Plaintext::from(Literal::Address(*validator));
+        match self.get_mapping_struct_field(&program_id, &mapping_name, &key, &field)? {
+            Some(value) => Ok(Some(value_to_commission(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Ensures the timestamp of the given candidate block is strictly greater than that of the latest block.
+    /// This is a cheap check that may be performed before `check_next_block`.
+    pub fn check_timestamp_increasing(&self, block: &Block<N>) -> Result<()> {
+        ensure_timestamp_increasing(self.latest_block().timestamp(), block.timestamp())
+            .map_err(|error| anyhow!("Invalid block {} - {error}", block.height()))
+    }
+
+    /// Ensures the number of transitions in the given transaction does not exceed the per-transaction maximum.
+    pub fn check_transition_count(&self, transaction: &Transaction<N>) -> Result<()> {
+        ensure_transition_count(transaction.transitions().count(), Transaction::<N>::MAX_TRANSITIONS)
+            .map_err(|error| anyhow!("Invalid transaction '{}' - {error}", transaction.id()))
+    }
+
+    /// Returns the number of solutions accepted in the blocks of the given epoch, up to the latest block.
+    pub fn epoch_solution_count(&self, epoch: u32) -> Result<u64> {
+        // Retrieve the block range of the epoch, up to the latest block.
+        let heights = self.epoch_height_range(epoch);
+        if heights.start > self.latest_block_height() {
+            bail!("Epoch {epoch} has not started");
+        }
+        let heights = heights.start..heights.end.min(self.latest_block_height().saturating_add(1));
+        // Sum the solutions of each block.
+        let mut count = 0u64;
+        for height in heights {
+            // If the Ctrl-C handler registered the signal, then stop the count.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the solution count at block {height} - The node is shutting down");
+            }
+            count += self.get_block_solution_count(height)? as u64;
+        }
+        Ok(count)
+    }
+
+    /// Ensures the changes from the committee for `from_round` to the committee for `to_round` are explained by
+    /// the staking transactions committed in the blocks between the rounds.
+    ///
+    /// Stake increases are not checked, as staking rewards increase the stake of the committee every block.
+    pub fn verify_committee_transition(&self, from_round: u64, to_round: u64) -> Result<()> {
+        // Ensure the rounds are in order.
+        if from_round > to_round {
+            bail!("Invalid committee transition from round {from_round} to round {to_round}");
+        }
+        // Retrieve the committees for the rounds.
+        let from = self.get_committee_for_round(from_round)?;
+        let to = self.get_committee_for_round(to_round)?;
+        // Retrieve the blocks after `from_round`, up to and including `to_round`.
+        let heights = self.find_first_height_at_or_above_round(from_round.saturating_add(1))?
+            ..self.find_first_height_at_or_above_round(to_round.saturating_add(1))?;
+        self.ensure_valid_block_range(&heights)?;
+        // Collect the addresses involved in the staking transactions of the blocks.
+        let candidates = from.members().keys().chain(to.members().keys()).copied().collect::<HashSet<_>>();
+        let mut explained = HashSet::new();
+        for height in heights {
+            // If the Ctrl-C handler registered the signal, then stop the scan.
+            if self.shutdown.load(Ordering::Relaxed) {
+                bail!("Skipping the staking scan at block {height} - The node is shutting down");
+            }
+            for transaction in self.ledger.get_transactions(height)?.iter() {
+                // Retrieve the staking futures of the transaction.
+                let futures = transaction
+                    .transaction()
+                    .transitions()
+                    .filter_map(|transition| match transition.outputs().last() {
+                        Some(Output::Future(_, Some(future))) if is_staking_future(future) => Some(future),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                if futures.is_empty() {
+                    continue;
+                }
+                // Collect the addresses in the arguments of the staking futures.
+                for argument in futures.iter().flat_map(|future| future.arguments()) {
+                    if let Argument::Plaintext(Plaintext::Literal(Literal::Address(address), _)) = argument {
+                        explained.insert(*address);
+                    }
+                }
+                // Collect the committee entries the transaction updated. An unbond from a delegator does not name
+                // the validator it is bonded to, but its finalize operations update the validator's committee entry.
+                explained.extend(updated_committee_members(transaction.finalize_operations(), &candidates)?);
+            }
+        }
+        // Ensure the changes to the committee are explained.
+        ensure_committee_changes_explained(&from, &to, &explained).map_err(|error| {
+            anyhow!("Invalid committee transition from round {from_round} to round {to_round} - {error}")
+        })
+    }
+
+    /// Returns the height of the block that deployed the given program, or `None` if it is not deployed.
+    /// The built-in `credits.aleo` program is not deployed by a transaction, and is reported at the genesis height.
+    pub fn get_program_deployment_height(&self, program_id: &ProgramID<N>) -> Result<Option<u32>> {
+        // The built-in program is available from the genesis block.
+        if *program_id == ProgramID::from_str("credits.aleo")? {
+            return Ok(Some(0));
+        }
+        // Retrieve the deployment transaction ID.
+        let Some(transaction_id) = self.ledger.vm().transaction_store().find_deployment_id(program_id)? else {
+            return Ok(None);
+        };
+        // Retrieve the height of the block that confirmed the deployment.
+        match self.ledger.find_block_hash(&transaction_id)? {
+            Some(block_hash) => Ok(Some(self.ledger.get_height(&block_hash)?)),
+            None => bail!("Missing the block for the deployment of '{program_id}' in transaction '{transaction_id}'"),
+        }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> fmt::Debug for CoreLedgerService<N, C> {
+    /// Implements a custom `fmt::Debug` for `CoreLedgerService`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CoreLedgerService").field("current_committee", &self.current_committee()).finish()
+    }
+}
+
+#[async_trait]
+impl<N: Network, C: ConsensusStorage<N>> LedgerService<N> for CoreLedgerService<N, C> {
+    /// Returns the latest round in the ledger.
+    fn latest_round(&self) -> u64 {
+        self.ledger.latest_round()
+    }
+
+    /// Returns the latest block height in the ledger.
+    fn latest_block_height(&self) -> u32 {
+        self.ledger.latest_height()
+    }
+
+    /// Returns the latest block in the ledger.
+    fn latest_block(&self) -> Block<N> {
+        self.ledger.latest_block()
+    }
+
+    /// Returns `true` if the given block height exists in the ledger.
+    fn contains_block_height(&self, height: u32) -> bool {
+        self.ledger.contains_block_height(height).unwrap_or(false)
+    }
+
+    /// Returns the block height for the given block hash, if it exists.
+    fn get_block_height(&self, hash: &N::BlockHash) -> Result<u32> {
+        self.ledger.get_height(hash)
+    }
+
+    /// Returns the block hash for the given block height, if it exists.
+    fn get_block_hash(&self, height: u32) -> Result<N::BlockHash> {
+        self.ledger.get_hash(height)
+    }
+
+    /// Returns the block for the given block height.
+    fn get_block(&self, height: u32) -> Result<Block<N>> {
+        self.ledger.get_block(height)
+    }
+
+    /// Returns the blocks in the given block range.
+    /// The range is inclusive of the start and exclusive of the end.
+    fn get_blocks(&self, heights: Range<u32>) -> Result<Vec<Block<N>>> {
+        self.ledger.get_blocks(heights)
+    }
+
+    /// Returns the solution for the given solution ID.
+    fn get_solution(&self, solution_id: &PuzzleCommitment<N>) -> Result<ProverSolution<N>> {
+        self.ledger.get_solution(solution_id)
+    }
+
+    /// Returns the unconfirmed transaction for the given transaction ID.
+    fn get_unconfirmed_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>> {
+        self.ledger.get_unconfirmed_transaction(&transaction_id)
+    }
+
+    /// Returns the batch certificate for the given batch certificate ID.
+    fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<BatchCertificate<N>> {
+        match self.ledger.get_batch_certificate(certificate_id) {
+            Ok(Some(certificate)) => Ok(certificate),
+            Ok(None) => bail!("No batch certificate found for certificate ID {certificate_id} in the ledger"),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns the current committee.
+    fn current_committee(&self) -> Result<Committee<N>> {
+        self.ledger.latest_committee()
+    }
+
+    /// Returns the committee for the given round.
+    /// If the given round is in the future, then the current committee is returned.
+    fn get_committee_for_round(&self, round: u64) -> Result<Committee<N>> {
+        match self.ledger.get_committee_for_round(round)? {
+            // Return the committee if it exists.
+            Some(committee) => Ok(committee),
+            // Return the current committee if the round is in the future.
+            None => {
+                // Retrieve the current committee.
+                let current_committee = self.current_committee()?;
+                // Return the current committee if the round is in the future.
+                match current_committee.starting_round() <= round {
+                    true => Ok(current_committee),
+                    false => bail!("No committee found for round {round} in the ledger"),
+                }
+            }
+        }
+    }
+
+    /// Returns the previous committee for the given round.
+    /// If the previous round is in the future, then the current committee is returned.
+    fn get_previous_committee_for_round(&self, round: u64) -> Result<Committee<N>> {
+        // Get the round number for the previous committee. Note, we subtract 2 from odd rounds,
+        // because committees are updated in even rounds.
+        let previous_round = match round % 2 == 0 {
+            true => round.saturating_sub(1),
+            false => round.saturating_sub(2),
+        };
+
+        // Retrieve the committee for the previous round.
+        self.get_committee_for_round(previous_round)
+    }
+
+    /// Returns `true` if the ledger contains the given certificate ID in block history.
+    fn contains_certificate(&self, certificate_id: &Field<N>) -> Result<bool> {
+        self.ledger.contains_certificate(certificate_id)
+    }
+
+    /// Returns `true` if the transmission exists in the ledger.
+    fn contains_transmission(&self, transmission_id: &TransmissionID<N>) -> Result<bool> {
+        match transmission_id {
+            TransmissionID::Ratification => Ok(false),
+            TransmissionID::Solution(puzzle_commitment) => self.ledger.contains_puzzle_commitment(puzzle_commitment),
+            TransmissionID::Transaction(transaction_id) => self.ledger.contains_transaction_id(transaction_id),
+        }
+    }
+
+    /// Ensures the given transmission ID matches the given transmission.
+    fn ensure_transmission_id_matches(
+        &self,
+        transmission_id: TransmissionID<N>,
+        transmission: &mut Transmission<N>,
+    ) -> Result<()> {
+        // Ensure the transmission is of the same type as the transmission ID, before deserializing it.
+        if !Self::transmission_id_variant_matches(&transmission_id, transmission) {
+            bail!("Mismatching `(transmission_id, transmission)` pair - {transmission_id}");
+        }
+        // Ensure the transmission is within the size limit, before deserializing it.
+        self.check_transmission_limits(&transmission_id, transmission)?;
+
+        match (transmission_id, transmission) {
+            (TransmissionID::Transaction(expected_transaction_id), Transmission::Transaction(transaction_data)) => {
+                match transaction_data.clone().deserialize_blocking() {
+                    Ok(transaction) => {
+                        if transaction.id() != expected_transaction_id {
+                            bail!(
+                                "Received mismatching transaction ID  - expected {}, found {}",
+                                fmt_id(expected_transaction_id),
+                                fmt_id(transaction.id()),
+                            );
+                        }
+
+                        // Update the transmission with the deserialized transaction.
+                        *transaction_data = Data::Object(transaction);
+                    }
+                    Err(err) => {
+                        bail!("Failed to deserialize transaction: {err}");
+                    }
+                }
+            }
+            (TransmissionID::Solution(expected_commitment), Transmission::Solution(solution_data)) => {
+                match solution_data.clone().deserialize_blocking() {
+                    Ok(solution) => {
+                        if solution.commitment() != expected_commitment {
+                            bail!(
+                                "Received mismatching solution ID - expected {}, found {}",
+                                fmt_id(expected_commitment),
+                                fmt_id(solution.commitment()),
+                            );
+                        }
+
+                        // Update the transmission with the deserialized solution.
+                        *solution_data = Data::Object(solution);
+                    }
+                    Err(err) => {
+                        bail!("Failed to deserialize solution: {err}");
+                    }
+                }
+            }
+            // Note: The variants are checked to match above, so the remaining pair is a ratification.
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Checks the given solution is well-formed.
+    async fn check_solution_basic(
+        &self,
+        puzzle_commitment: PuzzleCommitment<N>,
+        solution: Data<ProverSolution<N>>,
+    ) -> Result<()> {
+        // Deserialize the solution.
+        let solution = spawn_blocking!(solution.deserialize_blocking())?;
+        // Ensure the puzzle commitment matches in the solution.
+        if puzzle_commitment != solution.commitment() {
+            bail!("Invalid solution - expected {puzzle_commitment}, found {}", solution.commitment());
+        }
+
+        // Retrieve the solution verifier.
+        let solution_verifier = self.solution_verifier.clone();
+        // Retrieve the current epoch challenge.
+        let epoch_challenge = self.latest_epoch_challenge()?;
+        // Retrieve the current proof target.
+        let proof_target = self.ledger.latest_proof_target();
+
+        // Ensure that the prover solution is valid for the given epoch.
+        if !spawn_blocking!(solution_verifier.verify_solution(&solution, &epoch_challenge, proof_target))? {
+            bail!("Invalid prover solution '{puzzle_commitment}' for the current epoch.");
+        }
+        // Record the verification.
+        record_verification(&self.verified_solutions, Instant::now());
+        Ok(())
+    }
+
+    /// Checks the given transaction is well-formed and unique.
+    async fn check_transaction_basic(
+        &self,
+        transaction_id: N::TransactionID,
+        transaction: Data<Transaction<N>>,
+    ) -> Result<()> {
+        // Deserialize the transaction.
+        let transaction = spawn_blocking!(transaction.deserialize_blocking())?;
+        // Ensure the transaction ID matches in the transaction.
+        if transaction_id != transaction.id() {
+            bail!("Invalid transaction - expected {transaction_id}, found {}", transaction.id());
+        }
+        // Check if the transmission is a fee transaction.
+        if transaction.is_fee() {
+            bail!("Invalid transaction - 'Transaction::fee' type is not valid at this stage ({})", transaction.id());
+        }
+        // Ensure the transaction does not exceed the maximum number of transitions.
+        self.check_transition_count(&transaction)?;
+        // Ensure the transaction only uses programs in the allowlist.
+        self.check_program_allowlist(&transaction)?;
+        // Ensure the transaction does not redeploy a program.
+        self.check_deployment_unique(&transaction)?;
+        // Check the transaction is well-formed.
+        let ledger = self.ledger.clone();
+        spawn_blocking!(ledger.check_transaction_basic(&transaction, None, &mut rand::thread_rng()))?;
+        // Record the verification.
+        record_verification(&self.verified_transactions, Instant::now());
+        Ok(())
+    }
+
+    /// Checks the given block is valid next block.
+    fn check_next_block(&self, block: &Block<N>) -> Result<()> {
+        self.ledger.check_next_block(block, &mut rand::thread_rng())
+    }
+
+    /// Returns a candidate for the next block in the ledger, using a committed subdag and its transmissions.
+    #[cfg(feature = "ledger-write")]
+    fn prepare_advance_to_next_quorum_block(
+        &self,
+        subdag: Subdag<N>,
+        transmissions: IndexMap<TransmissionID<N>, Transmission<N>>,
+    ) -> Result<Block<N>> {
+        // Ensure the certificates of the subdag reach quorum in their committees.
+        self.verify_subdag_committees(&subdag)?;
+        self.ledger.prepare_advance_to_next_quorum_block(subdag, transmissions)
+    }
+
+    /// Adds the given block as the next block in the ledger.
+    #[cfg(feature = "ledger-write")]
+    fn advance_to_next_block(&self, block: &Block<N>) -> Result<()> {
+        // If the Ctrl-C handler registered the signal, then skip advancing to the next block.
+        if self.shutdown.load(Ordering::Relaxed) {
+            bail!("Skipping advancing to block {} - The node is shutting down", block.height());
+        }
+        // Advance to the next block.
+        self.ledger.advance_to_next_block(block)?;
+        tracing::info!("\n\nAdvanced to block {} at round {} - {}\n", block.height(), block.round(), block.hash());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::{
+        ledger::store::{helpers::memory::ConsensusMemory, ConsensusStore},
+        prelude::{PrivateKey, Rng, TestRng, Testnet3, Uniform},
+        synthesizer::VM,
+    };
+
+    use aleo_std::StorageMode;
+    use std::sync::OnceLock;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentLedgerService = CoreLedgerService<CurrentNetwork, ConsensusMemory<CurrentNetwork>>;
+
+    /// Initializes a core ledger service with the genesis block of the network.
+    fn sample_ledger_service() -> CurrentLedgerService {
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        // Initialize the ledger.
+        let ledger = Ledger::load(genesis, StorageMode::Production).unwrap();
+        // Initialize the ledger service.
+        CoreLedgerService::new(ledger, Default::default())
+    }
+
+    /// Returns the private keys of the committee members of `sample_quorum_genesis_block`.
+    fn sample_committee_private_keys() -> Vec<PrivateKey<CurrentNetwork>> {
+        (0..4).map(|seed| PrivateKey::new(&mut TestRng::fixed(seed)).unwrap()).collect()
+    }
+
+    /// Returns a genesis block whose committee consists of `sample_committee_private_keys`.
+    /// As proving the genesis transactions is expensive, the block is only computed once.
+    fn sample_quorum_genesis_block() -> Block<CurrentNetwork> {
+        static GENESIS: OnceLock<Block<CurrentNetwork>> = OnceLock::new();
+        GENESIS
+            .get_or_init(|| {
+                let private_keys = sample_committee_private_keys();
+                // Construct the committee.
+                let members = private_keys
+                    .iter()
+                    .map(|private_key| (Address::try_from(private_key).unwrap(), (2 * MIN_VALIDATOR_STAKE, true)))
+                    .collect::<IndexMap<_, _>>();
+                let committee = Committee::new(0, members).unwrap();
+                // Distribute the remaining supply as public balances, with the leftover to the first member.
+                let remaining_supply = CurrentNetwork::STARTING_SUPPLY - committee.total_stake();
+                let public_balance = remaining_supply / private_keys.len() as u64;
+                let mut public_balances =
+                    committee.members().keys().map(|address| (*address, public_balance)).collect::<IndexMap<_, _>>();
+                *public_balances.get_index_mut(0).unwrap().1 += remaining_supply % private_keys.len() as u64;
+                // Initialize the genesis block.
+                let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+                let vm = VM::from(store).unwrap();
+                vm.genesis_quorum(&private_keys[0], committee, public_balances, &mut TestRng::fixed(0)).unwrap()
+            })
+            .clone()
+    }
+
+    /// Returns a certificate of the given transmission IDs for the given round, authored by the given
+    /// committee member, and signed by each of the other committee members.
+    fn sample_signed_certificate(
+        private_keys: &[PrivateKey<CurrentNetwork>],
+        author: &PrivateKey<CurrentNetwork>,
+        round: u64,
+        timestamp: i64,
+        transmission_ids: IndexSet<TransmissionID<CurrentNetwork>>,
+        previous_certificate_ids: IndexSet<Field<CurrentNetwork>>,
+        rng: &mut TestRng,
+    ) -> BatchCertificate<CurrentNetwork> {
+        // Sign the batch header.
+        let batch_header = BatchHeader::new(
+            author,
+            round,
+            timestamp,
+            transmission_ids,
+            previous_certificate_ids,
+            Default::default(),
+            rng,
+        )
+        .unwrap();
+        // Sign the batch ID with each of the other committee members.
+        let signatures = private_keys
+            .iter()
+            .filter(|private_key| *private_key != author)
+            .map(|private_key| private_key.sign(&[batch_header.batch_id()], rng).unwrap())
+            .collect();
+        BatchCertificate::from(batch_header, signatures).unwrap()
+    }
+
+    /// Returns a candidate for the next block of the given ledger service, which commits an empty certificate
+    /// from each committee member in the round before the next anchor round, and the anchor certificate
+    /// of the elected leader.
+    fn sample_next_quorum_block(
+        ledger_service: &CurrentLedgerService,
+        private_keys: &[PrivateKey<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> Block<CurrentNetwork> {
+        sample_next_quorum_block_with_transactions(ledger_service, private_keys, vec![], rng)
+    }
+
+    /// Returns a candidate for the next block of the given ledger service, as in `sample_next_quorum_block`,
+    /// whose anchor certificate includes the given transactions.
+    fn sample_next_quorum_block_with_transactions(
+        ledger_service: &CurrentLedgerService,
+        private_keys: &[PrivateKey<CurrentNetwork>],
+        transactions: Vec<Transaction<CurrentNetwork>>,
+        rng: &mut TestRng,
+    ) -> Block<CurrentNetwork> {
+        // Retrieve the next anchor round.
+        let round = next_anchor_round(ledger_service.latest_round());
+        let timestamp = ledger_service.latest_block().timestamp() + 1;
+        // Link to the anchor certificate of the latest block, if it is from two rounds before.
+        let previous_certificate_ids = match ledger_service.latest_block().authority() {
+            Authority::Quorum(subdag) if subdag.anchor_round() + 2 == round => {
+                [subdag.leader_certificate().id()].into_iter().collect()
+            }
+            _ => IndexSet::new(),
+        };
+        // Certify the round before the anchor round, with each committee member.
+        let certificates = private_keys
+            .iter()
+            .map(|author| {
+                sample_signed_certificate(
+                    private_keys,
+                    author,
+                    round - 1,
+                    timestamp,
+                    IndexSet::new(),
+                    previous_certificate_ids.clone(),
+                    rng,
+                )
+            })
+            .collect::<IndexSet<_>>();
+        // Certify the anchor round, with the elected leader.
+        let leader = ledger_service.get_previous_committee_for_round(round).unwrap().get_leader(round).unwrap();
+        let leader =
+            private_keys.iter().find(|private_key| Address::try_from(*private_key).unwrap() == leader).unwrap();
+        let transmissions = transactions
+            .into_iter()
+            .map(|transaction| {
+                (TransmissionID::from(&transaction.id()), Transmission::Transaction(Data::Object(transaction)))
+            })
+            .collect::<IndexMap<_, _>>();
+        let transmission_ids = transmissions.keys().copied().collect();
+        let previous_certificate_ids = certificates.iter().map(BatchCertificate::id).collect();
+        let anchor = sample_signed_certificate(
+            private_keys,
+            leader,
+            round,
+            timestamp,
+            transmission_ids,
+            previous_certificate_ids,
+            rng,
+        );
+        // Construct the subdag.
+        let subdag = [(round - 1, certificates), (round, [anchor].into_iter().collect())].into_iter().collect();
+        let subdag = Subdag::from(subdag, Default::default()).unwrap();
+        // Prepare the block.
+        ledger_service.ledger.prepare_advance_to_next_quorum_block(subdag, transmissions).unwrap()
+    }
+
+    /// Returns a transaction that executes the given `credits.aleo` function on the given inputs, without a fee.
+    fn sample_credits_execution(
+        ledger_service: &CurrentLedgerService,
+        private_key: &PrivateKey<CurrentNetwork>,
+        function_name: &str,
+        inputs: &[&str],
+        rng: &mut TestRng,
+    ) -> Transaction<CurrentNetwork> {
+        let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input).unwrap()).collect::<Vec<_>>();
+        ledger_service
+            .ledger
+            .vm()
+            .execute(private_key, ("credits.aleo", function_name), inputs.iter(), None, 0, None, rng)
+            .unwrap()
+    }
+
+    /// Initializes a core ledger service as in `sample_advanced_ledger_service`, in which the first committee member
+    /// unbonds the given amount in the latest block, along with the address of that member.
+    fn sample_unbonding_ledger_service(
+        amount: u64,
+        rng: &mut TestRng,
+    ) -> (CurrentLedgerService, Address<CurrentNetwork>) {
+        let (ledger_service, private_keys) = sample_advanced_ledger_service(1, rng);
+        // Unbond the amount from the first committee member.
+        let transaction = sample_credits_execution(
+            &ledger_service,
+            &private_keys[0],
+            "unbond_public",
+            &[&format!("{amount}u64")],
+            rng,
+        );
+        let block = sample_next_quorum_block_with_transactions(&ledger_service, &private_keys, vec![transaction], rng);
+        ledger_service.ledger.advance_to_next_block(&block).unwrap();
+        (ledger_service, Address::try_from(&private_keys[0]).unwrap())
+    }
+
+    /// Initializes a core ledger service with `sample_quorum_genesis_block`, advanced by the given number
+    /// of blocks from `sample_next_quorum_block`, along with the private keys of the committee members.
+    fn sample_advanced_ledger_service(
+        num_blocks: u32,
+        rng: &mut TestRng,
+    ) -> (CurrentLedgerService, Vec<PrivateKey<CurrentNetwork>>) {
+        let private_keys = sample_committee_private_keys();
+        // Initialize the ledger.
+        let ledger = Ledger::load(sample_quorum_genesis_block(), StorageMode::Production).unwrap();
+        let ledger_service = CoreLedgerService::new(ledger, Default::default());
+        // Advance the ledger.
+        for _ in 0..num_blocks {
+            let block = sample_next_quorum_block(&ledger_service, &private_keys, rng);
+            ledger_service.ledger.advance_to_next_block(&block).unwrap();
+        }
+        (ledger_service, private_keys)
+    }
+
+    /// A solution verifier that returns a fixed result.
+    struct FixedVerifier(bool);
+
+    impl SolutionVerifier<CurrentNetwork> for FixedVerifier {
+        fn verify_solution(
+            &self,
+            _solution: &ProverSolution<CurrentNetwork>,
+            _epoch_challenge: &EpochChallenge<CurrentNetwork>,
+            _proof_target: u64,
+        ) -> Result<bool> {
+            Ok(self.0)
+        }
+    }
+
+    /// Samples a committee with one member for each of the given stakes.
+    fn sample_committee(round: u64, stakes: &[u64], rng: &mut TestRng) -> Committee<CurrentNetwork> {
+        // Sample the members.
+        let members = stakes
+            .iter()
+            .map(|stake| (Address::try_from(PrivateKey::new(rng).unwrap()).unwrap(), (*stake, false)))
+            .collect::<IndexMap<_, _>>();
+        // Initialize the committee.
+        Committee::new(round, members).unwrap()
+    }
+
+    /// Samples a prover solution for the latest epoch of the given ledger service.
+    fn sample_solution(ledger_service: &CurrentLedgerService, rng: &mut TestRng) -> ProverSolution<CurrentNetwork> {
+        // Sample an address.
+        let address = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        // Retrieve the latest epoch challenge.
+        let epoch_challenge = ledger_service.ledger.latest_epoch_challenge().unwrap();
+        // Compute the prover solution.
+        ledger_service.ledger.coinbase_puzzle().prove(&epoch_challenge, address, rng.gen(), None).unwrap()
+    }
+
+    #[test]
+    fn test_transmission_set_root() {
+        let rng = &mut TestRng::default();
+
+        // Sample a certificate, and retrieve its transmission IDs.
+        let certificate = snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate(rng);
+        let ids = certificate.transmission_ids().iter().copied().collect::<Vec<_>>();
+        assert!(!ids.is_empty());
+
+        // Compute the root.
+        let root = CurrentLedgerService::transmission_set_root(&ids).unwrap();
+        assert_ne!(root, Field::zero());
+
+        // Ensure the root does not depend on the order of the transmission IDs.
+        let mut reversed = ids.clone();
+        reversed.reverse();
+        assert_eq!(root, CurrentLedgerService::transmission_set_root(&reversed).unwrap());
+        for shift in 0..ids.len() {
+            let mut rotated = ids.clone();
+            rotated.rotate_left(shift);
+            assert_eq!(root, CurrentLedgerService::transmission_set_root(&rotated).unwrap());
+        }
+        // Ensure the root is reproduced from the transmission IDs as another node decodes them.
+        let decoded = ids
+            .iter()
+            .map(|id| TransmissionID::<CurrentNetwork>::from_bytes_le(&id.to_bytes_le().unwrap()).unwrap())
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(root, CurrentLedgerService::transmission_set_root(&decoded).unwrap());
+
+        // Ensure the root ignores duplicate transmission IDs.
+        let mut duplicated = ids.clone();
+        duplicated.push(ids[0]);
+        assert_eq!(root, CurrentLedgerService::transmission_set_root(&duplicated).unwrap());
+
+        // Ensure the root changes when the set changes.
+        assert_ne!(root, CurrentLedgerService::transmission_set_root(&ids[1..]).unwrap());
+
+        // Ensure the root of the empty set is zero.
+        assert_eq!(CurrentLedgerService::transmission_set_root(&[]).unwrap(), Field::zero());
+
+        // Ensure the transmission IDs are the ones the certificate commits to in its batch ID.
+        let header = certificate.batch_header();
+        let batch_id = |transmission_ids: &IndexSet<TransmissionID<CurrentNetwork>>| {
+            BatchHeader::compute_batch_id(
+                header.author(),
+                header.round(),
+                header.timestamp(),
+                transmission_ids,
+                header.previous_certificate_ids(),
+                header.last_election_certificate_ids(),
+            )
+            .unwrap()
+        };
+        assert_eq!(batch_id(&ids.iter().copied().collect()), certificate.id());
+        // Ensure the batch ID, unlike the root, commits to the order of the transmission IDs.
+        if ids.len() > 1 {
+            assert_ne!(batch_id(&reversed.iter().copied().collect()), certificate.id());
+        }
+    }
+
+    #[test]
+    fn test_get_certificate_block_height() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure an unknown certificate has no block height.
+        let certificate_id = Field::rand(rng);
+        assert!(!ledger_service.contains_certificate(&certificate_id).unwrap());
+        assert_eq!(ledger_service.get_certificate_block_height(&certificate_id).unwrap(), None);
+
+        // Ensure the genesis block is the first block at or above the genesis round.
+        let genesis_round = ledger_service.latest_block().round();
+        assert_eq!(ledger_service.find_first_height_at_or_above_round(genesis_round).unwrap(), 0);
+        // Ensure a future round has no block yet.
+        assert_eq!(ledger_service.find_first_height_at_or_above_round(genesis_round + 1).unwrap(), 1);
+
+        // Ensure each committed certificate resolves to the block that committed it.
+        let (ledger_service, _) = sample_advanced_ledger_service(2, rng);
+        for height in 1..=ledger_service.latest_block_height() {
+            let certificate_ids = ledger_service.get_block_certificate_ids(height).unwrap();
+            assert!(!certificate_ids.is_empty());
+            for certificate_id in certificate_ids {
+                assert_eq!(ledger_service.get_certificate_block_height(&certificate_id).unwrap(), Some(height));
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_program_allowlist() {
+        let rng = &mut TestRng::default();
+
+        // Sample an execution transaction.
+        let transaction =
+            snarkvm::ledger::block::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let Transaction::Execute(_, execution, _) = &transaction else { unreachable!() };
+        // Retrieve the programs executed by the transaction.
+        let executed_programs =
+            execution.transitions().map(|transition| *transition.program_id()).collect::<HashSet<_>>();
+
+        // Ensure the transaction is accepted without an allowlist.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.check_program_allowlist(&transaction).is_ok());
+
+        // Ensure the transaction is accepted with an allowlist containing its programs.
+        let ledger_service = sample_ledger_service().with_program_allowlist(executed_programs);
+        assert!(ledger_service.check_program_allowlist(&transaction).is_ok());
+
+        // Ensure the transaction is rejected with an allowlist excluding its programs.
+        let program_id = ProgramID::from_str("allowed.aleo").unwrap();
+        let ledger_service = sample_ledger_service().with_program_allowlist(HashSet::from([program_id]));
+        assert!(ledger_service.check_program_allowlist(&transaction).is_err());
+    }
+
+    #[test]
+    fn test_block_times() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure the elapsed time from a block to itself is zero.
+        assert_eq!(ledger_service.inter_block_time(0, 0).unwrap(), 0);
+        // Ensure the elapsed time to a missing block fails.
+        assert!(ledger_service.inter_block_time(0, 1).is_err());
+
+        // Ensure the average block time requires at least two blocks.
+        assert!(ledger_service.average_block_time(0..0).is_err());
+        assert!(ledger_service.average_block_time(0..1).is_err());
+        // Ensure the average block time rejects missing blocks.
+        assert!(ledger_service.average_block_time(0..2).is_err());
+        // Ensure the average block time rejects unbounded ranges.
+        assert!(ledger_service.average_block_time(0..MAX_BLOCK_RANGE + 1).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stream_confirmed_transactions() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Retrieve the number of transactions in the genesis block.
+        let num_transactions = ledger_service.latest_block().transactions().len();
+
+        // Stream the transactions of the genesis block.
+        let (tx, mut rx) = mpsc::channel(4);
+        let handle = tokio::spawn(async move { ledger_service.stream_confirmed_transactions(0..1, tx).await });
+
+        // Ensure every transaction is streamed with its block height.
+        let mut count = 0;
+        while let Some(result) = rx.recv().await {
+            let (height, _) = result.unwrap();
+            assert_eq!(height, 0);
+            count += 1;
+        }
+        handle.await.unwrap();
+        assert_eq!(count, num_transactions);
+    }
+
+    #[test]
+    fn test_missing_certificate_parents() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Sample a certificate with previous certificates.
+        let (certificate, previous_certificates) =
+            snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate_with_previous_certificates(
+                3, rng,
+            );
+        assert!(!previous_certificates.is_empty());
+
+        // Ensure all of the previous certificates are reported as missing, in order.
+        let missing_parents = ledger_service.missing_certificate_parents(&certificate).unwrap();
+        assert_eq!(missing_parents, certificate.previous_certificate_ids().iter().copied().collect::<Vec<_>>());
+        // Ensure each missing parent is one of the previous certificates.
+        for missing_parent in missing_parents {
+            assert!(previous_certificates.iter().any(|previous| previous.id() == missing_parent));
+        }
+
+        // Ensure a certificate without previous certificates has no missing parents.
+        for previous_certificate in previous_certificates {
+            if previous_certificate.previous_certificate_ids().is_empty() {
+                assert!(ledger_service.missing_certificate_parents(&previous_certificate).unwrap().is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn test_weighted_median() {
+        // Ensure the median of no values is undefined.
+        assert_eq!(weighted_median([]), None);
+        assert_eq!(weighted_median([(10, 0)]), None);
+
+        // Ensure the median is weighed by stake across three validators.
+        assert_eq!(weighted_median([(30, 300), (10, 100), (20, 200)]), Some(20));
+        assert_eq!(weighted_median([(30, 500), (10, 100), (20, 100)]), Some(30));
+        assert_eq!(weighted_median([(30, 100), (10, 500), (20, 100)]), Some(10));
+        // Ensure zero-weight values are ignored.
+        assert_eq!(weighted_median([(30, 0), (10, 100), (20, 100)]), Some(10));
+
+        // Ensure a round without committed certificates has no median timestamp.
+        let ledger_service = sample_ledger_service();
+        let round = ledger_service.latest_round() + 2;
+        assert!(ledger_service.get_certificates_for_round(round).unwrap().is_empty());
+        assert!(ledger_service.weighted_median_timestamp(round).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_check_solution_basic_with_custom_verifier() {
+        let rng = &mut TestRng::default();
+
+        // Sample a solution.
+        let solution = sample_solution(&sample_ledger_service(), rng);
+        let commitment = solution.commitment();
+
+        // Ensure a verifier that always accepts is used in place of the coinbase verifying key.
+        let ledger_service = sample_ledger_service().with_solution_verifier(Arc::new(FixedVerifier(true)));
+        assert!(ledger_service.check_solution_basic(commitment, Data::Object(solution.clone())).await.is_ok());
+
+        // Ensure a verifier that always rejects is used in place of the coinbase verifying key.
+        let ledger_service = sample_ledger_service().with_solution_verifier(Arc::new(FixedVerifier(false)));
+        assert!(ledger_service.check_solution_basic(commitment, Data::Object(solution)).await.is_err());
+    }
+
+    #[test]
+    fn test_anchor_signers() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure the genesis block, which is not a quorum block, has no anchor signers.
+        assert!(ledger_service.anchor_signers(0).is_err());
+        // Ensure a missing block has no anchor signers.
+        assert!(ledger_service.anchor_signers(1).is_err());
+
+        // Ensure the anchor signers of a quorum block are the committee members for the anchor round.
+        let rng = &mut TestRng::default();
+        let (ledger_service, _) = sample_advanced_ledger_service(1, rng);
+        let block = ledger_service.latest_block();
+        let Authority::Quorum(subdag) = block.authority() else { panic!("Expected a quorum block") };
+        let anchor = subdag.leader_certificate();
+        let committee = ledger_service.get_previous_committee_for_round(anchor.round()).unwrap();
+        let signers = ledger_service.anchor_signers(block.height()).unwrap();
+        assert_eq!(signers[0], anchor.author());
+        assert_eq!(signers.len(), committee.num_members());
+        for signer in &signers {
+            assert!(committee.is_committee_member(*signer));
+        }
+    }
+
+    #[test]
+    fn test_participation_rate() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee of four validators with equal stake.
+        let committee = sample_committee(0, &[MIN_VALIDATOR_STAKE; 4], rng);
+        let members = committee.members().keys().copied().collect::<Vec<_>>();
+
+        // Ensure the participation rate reflects the absent validators.
+        let rate = stake_fraction(&committee, members[..2].to_vec());
+        assert_eq!(rate, 0.5);
+        // Ensure duplicate and non-member authors do not count towards participation.
+        let outsider = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert_eq!(stake_fraction(&committee, [members[0], members[0], members[1], outsider]), rate);
+        // Ensure full participation is reported.
+        assert_eq!(stake_fraction(&committee, members), 1.0);
+
+        // Ensure a round without committed certificates has no participation.
+        let ledger_service = sample_ledger_service();
+        assert_eq!(ledger_service.participation_rate(ledger_service.latest_round() + 2).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_check_intra_block_conflicts() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transaction.
+        let transaction =
+            snarkvm::ledger::block::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        assert!(transaction.serial_numbers().next().is_some());
+
+        // Ensure a transaction does not conflict with itself.
+        assert!(ensure_unique_serial_numbers([&transaction]).is_ok());
+        // Ensure two transactions spending the same input conflict.
+        assert!(ensure_unique_serial_numbers([&transaction, &transaction]).is_err());
+
+        // Ensure the genesis block has no conflicts.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.check_intra_block_conflicts(&ledger_service.latest_block()).is_ok());
+    }
+
+    #[test]
+    fn test_last_authored_round() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure an address without certificates has not authored any round.
+        let address = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert_eq!(ledger_service.last_authored_round(&address, 0).unwrap(), None);
+        assert_eq!(ledger_service.last_authored_round(&address, u64::MAX).unwrap(), None);
+
+        // Ensure the last authored round of each committee member is found in the latest block, where the leader
+        // authored the anchor round, and the other members authored the round before it.
+        let (ledger_service, _) = sample_advanced_ledger_service(3, rng);
+        let latest_round = ledger_service.latest_round();
+        let Authority::Quorum(subdag) = ledger_service.latest_block().authority() else {
+            panic!("Expected a quorum block")
+        };
+        let leader = subdag.leader_certificate().author();
+        for address in ledger_service.current_committee().unwrap().members().keys() {
+            let expected_round = if *address == leader { latest_round } else { latest_round - 1 };
+            assert_eq!(ledger_service.last_authored_round(address, u64::MAX).unwrap(), Some(expected_round));
+            assert_eq!(ledger_service.last_authored_round(address, 1).unwrap(), Some(expected_round));
+            // Ensure a round before the search window is not found.
+            if *address != leader {
+                assert_eq!(ledger_service.last_authored_round(address, 0).unwrap(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_transmission_limits() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transaction and a solution.
+        let transaction =
+            snarkvm::ledger::block::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction_id = TransmissionID::Transaction(transaction.id());
+        let transaction_object = Transmission::Transaction(Data::Object(transaction.clone()));
+        let transaction = Transmission::Transaction(Data::Buffer(transaction.to_bytes_le().unwrap().into()));
+        let solution = sample_solution(&sample_ledger_service(), rng);
+        let solution_id = TransmissionID::Solution(solution.commitment());
+        let solution_object = Transmission::Solution(Data::Object(solution));
+        let solution = Transmission::Solution(Data::Buffer(solution.to_bytes_le().unwrap().into()));
+
+        // Ensure the transmissions are within the default limits.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.check_transmission_limits(&transaction_id, &transaction).is_ok());
+        assert!(ledger_service.check_transmission_limits(&solution_id, &solution).is_ok());
+        assert!(ledger_service
+            .check_transmission_limits(&TransmissionID::Ratification, &Transmission::Ratification)
+            .is_ok());
+
+        // Ensure an oversized transaction is rejected.
+        let ledger_service = sample_ledger_service().with_transmission_limits(DEFAULT_MAX_SOLUTION_BYTES, 1);
+        assert!(ledger_service.check_transmission_limits(&transaction_id, &transaction).is_err());
+        assert!(ledger_service.check_transmission_limits(&solution_id, &solution).is_ok());
+
+        // Ensure an oversized solution is rejected.
+        let ledger_service = sample_ledger_service().with_transmission_limits(1, DEFAULT_MAX_TRANSACTION_BYTES);
+        assert!(ledger_service.check_transmission_limits(&transaction_id, &transaction).is_ok());
+        assert!(ledger_service.check_transmission_limits(&solution_id, &solution).is_err());
+
+        // Ensure deserialized transmissions are not measured.
+        let ledger_service = sample_ledger_service().with_transmission_limits(1, 1);
+        assert!(ledger_service.check_transmission_limits(&transaction_id, &transaction_object).is_ok());
+        assert!(ledger_service.check_transmission_limits(&solution_id, &solution_object).is_ok());
+    }
+
+    #[test]
+    fn test_get_block_hashes() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure each block hash matches the block hash for its height.
+        let hashes = ledger_service.get_block_hashes(0..1).unwrap();
+        assert_eq!(hashes.len(), 1);
+        for (height, hash) in (0..).zip(hashes) {
+            assert_eq!(hash, ledger_service.get_block_hash(height).unwrap());
+        }
+        // Ensure an empty range has no block hashes.
+        assert!(ledger_service.get_block_hashes(0..0).unwrap().is_empty());
+        // Ensure a range with missing blocks fails.
+        assert!(ledger_service.get_block_hashes(0..2).is_err());
+        // Ensure an unbounded range fails.
+        assert!(ledger_service.get_block_hashes(0..MAX_BLOCK_RANGE + 1).is_err());
+    }
+
+    #[test]
+    fn test_find_fork_point() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let genesis_hash = ledger_service.get_block_hash(0).unwrap();
+        let unknown_hash = <CurrentNetwork as Network>::BlockHash::from(Field::rand(rng));
+
+        // Ensure matching locators return the highest matching height.
+        assert_eq!(ledger_service.find_fork_point(&[(0, genesis_hash)]).unwrap(), Some(0));
+        assert_eq!(ledger_service.find_fork_point(&[(5, unknown_hash), (0, genesis_hash)]).unwrap(), Some(0));
+        // Ensure diverging locators return no height.
+        assert_eq!(ledger_service.find_fork_point(&[(5, unknown_hash), (0, unknown_hash)]).unwrap(), None);
+        assert_eq!(ledger_service.find_fork_point(&[]).unwrap(), None);
+        // Ensure locators out of order fail.
+        assert!(ledger_service.find_fork_point(&[(0, genesis_hash), (5, unknown_hash)]).is_err());
+    }
+
+    #[test]
+    fn test_get_block_solutions() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure the solutions match those of the genesis block.
+        let solutions = ledger_service.get_block_solutions(0).unwrap();
+        let expected = ledger_service.latest_block().solutions().map_or(0, |solutions| solutions.len());
+        assert_eq!(solutions.len(), expected);
+        // Ensure a missing block fails.
+        assert!(ledger_service.get_block_solutions(1).is_err());
+    }
+
+    #[test]
+    fn test_supply() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure the genesis supply is accounted for by the public balances and the bonded stake.
+        let total_supply = ledger_service.total_supply().unwrap();
+        let circulating_supply = ledger_service.circulating_supply().unwrap();
+        let committee = ledger_service.current_committee().unwrap();
+        assert_eq!(total_supply, <CurrentNetwork as Network>::STARTING_SUPPLY);
+        assert_eq!(circulating_supply + committee.total_stake(), total_supply);
+
+        // Ensure the supply is cached at the latest block.
+        let (hash, supply) = ledger_service.supply_cache.read().unwrap();
+        assert_eq!(hash, ledger_service.latest_block().hash());
+        assert_eq!(supply, (total_supply, circulating_supply));
+    }
+
+    #[test]
+    fn test_verify_certificate_signatures() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Sample two certificates.
+        let certificate = snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate(rng);
+        let other = snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate(rng);
+        assert_ne!(certificate.batch_id(), other.batch_id());
+
+        // Ensure the signatures of a well-formed certificate are valid.
+        assert!(ledger_service.verify_certificate_signatures(&certificate).is_ok());
+
+        // Tamper with the certificate by replacing a signature with one over a different batch.
+        let mut signatures = certificate.signatures().cloned().collect::<IndexSet<_>>();
+        signatures.pop();
+        signatures.insert(*other.signatures().next().unwrap());
+        let tampered = BatchCertificate::from_unchecked(certificate.batch_header().clone(), signatures).unwrap();
+        // Ensure the tampered signature is detected.
+        assert!(ledger_service.verify_certificate_signatures(&tampered).is_err());
+
+        // Ensure signers outside of the committee do not reach quorum.
+        assert!(ledger_service.verify_certificate_quorum(&certificate).is_err());
+        assert!(ledger_service.verify_certificate(&certificate).is_err());
+    }
+
+    #[test]
+    fn test_get_round_dag() {
+        let rng = &mut TestRng::default();
+
+        // Sample a certificate with previous certificates.
+        let (certificate, previous_certificates) =
+            snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate_with_previous_certificates(
+                3, rng,
+            );
+
+        // Initialize the DAG for the round, including a certificate from another round.
+        let dag = RoundDag::new(3, &[certificate.clone(), previous_certificates[0].clone()]);
+        assert_eq!(dag.round, 3);
+        assert_eq!(dag.certificates.len(), 1);
+        // Ensure the parents of the certificate reference the previous round certificates.
+        let (certificate_id, author, parents) = &dag.certificates[0];
+        assert_eq!(*certificate_id, certificate.id());
+        assert_eq!(*author, certificate.author());
+        assert!(!parents.is_empty());
+        for parent in parents {
+            let previous = previous_certificates.iter().find(|previous| previous.id() == *parent).unwrap();
+            assert_eq!(previous.round(), 2);
+        }
+
+        // Ensure a round without committed certificates has an empty DAG.
+        let ledger_service = sample_ledger_service();
+        let round = ledger_service.latest_round() + 2;
+        assert_eq!(ledger_service.get_round_dag(round).unwrap(), RoundDag { round, certificates: vec![] });
+    }
+
+    #[test]
+    fn test_ensure_contiguous_rounds() {
+        let rng = &mut TestRng::default();
+
+        // Sample a certificate with previous certificates.
+        let (certificate, previous_certificates) =
+            snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate_with_previous_certificates(
+                3, rng,
+            );
+        let previous_certificates = previous_certificates.into_iter().collect::<IndexSet<_>>();
+
+        // Ensure well-ordered rounds are accepted.
+        let rounds = BTreeMap::from([(2, previous_certificates.clone()), (3, IndexSet::from([certificate.clone()]))]);
+        assert!(ensure_contiguous_rounds(&rounds).is_ok());
+        // Ensure certificates in the wrong round are rejected.
+        let rounds = BTreeMap::from([(2, IndexSet::from([certificate.clone()])), (3, previous_certificates.clone())]);
+        assert!(ensure_contiguous_rounds(&rounds).is_err());
+        // Ensure non-contiguous rounds are rejected.
+        let rounds = BTreeMap::from([(2, previous_certificates.clone()), (4, IndexSet::from([certificate.clone()]))]);
+        assert!(ensure_contiguous_rounds(&rounds).is_err());
+        // Ensure empty rounds are rejected.
+        let rounds = BTreeMap::from([(2, previous_certificates), (3, IndexSet::new())]);
+        assert!(ensure_contiguous_rounds(&rounds).is_err());
+    }
+
+    #[test]
+    fn test_sync_progress() {
+        // Initialize the ledger service, which is at the genesis block.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure the ledger is synced to the genesis block only.
+        assert!(ledger_service.is_synced_to(0));
+        assert!(!ledger_service.is_synced_to(1));
+        // Ensure the sync progress is relative to the target height.
+        assert_eq!(ledger_service.sync_progress(0), 1.0);
+        assert_eq!(ledger_service.sync_progress(1), 0.0);
+        assert_eq!(ledger_service.sync_progress(100), 0.0);
+    }
+
+    #[test]
+    fn test_cumulative_rewards() {
+        // Ensure rewards are split in proportion to the share.
+        assert_eq!(proportional_share(1000, 1, 4), 250);
+        assert_eq!(proportional_share(1000, 1, 3), 333);
+        assert_eq!(proportional_share(u64::MAX, 1, 1), u64::MAX);
+        assert_eq!(proportional_share(1000, 0, 4), 0);
+        assert_eq!(proportional_share(1000, 1, 0), 0);
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let committee = ledger_service.current_committee().unwrap();
+        let validator = *committee.members().keys().next().unwrap();
+
+        // Ensure the genesis block does not reward the validator.
+        assert_eq!(ledger_service.validator_reward_share(&validator, 0).unwrap(), 0);
+        assert_eq!(ledger_service.cumulative_rewards(&validator, 0..1).unwrap(), 0);
+        // Ensure missing blocks and unbounded ranges fail.
+        assert!(ledger_service.cumulative_rewards(&validator, 0..2).is_err());
+        assert!(ledger_service.cumulative_rewards(&validator, 0..MAX_BLOCK_RANGE + 1).is_err());
+    }
+
+    #[test]
+    fn test_check_coinbase_target() {
+        // Ensure solutions that fall short of the coinbase target accumulate.
+        assert_eq!(expected_cumulative_proof_target(0, 40, 100), 40);
+        assert_eq!(expected_cumulative_proof_target(40, 59, 100), 99);
+        // Ensure solutions that meet the coinbase target reset the cumulative proof target.
+        assert_eq!(expected_cumulative_proof_target(40, 60, 100), 0);
+        assert_eq!(expected_cumulative_proof_target(0, 150, 100), 0);
+        // Ensure a block without solutions carries over the cumulative proof target.
+        assert_eq!(expected_cumulative_proof_target(40, 0, 100), 40);
+
+        // Ensure the genesis block, which has no previous block, passes.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.check_coinbase_target(&ledger_service.latest_block()).is_ok());
+    }
+
+    #[test]
+    fn test_epoch_number() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let epoch_length = <CurrentNetwork as Network>::NUM_BLOCKS_PER_EPOCH;
+
+        // Ensure the genesis block is in the first epoch.
+        assert_eq!(ledger_service.latest_epoch_number(), 0);
+        // Ensure the epoch boundaries are at multiples of the epoch length.
+        assert_eq!(ledger_service.epoch_for_height(0), 0);
+        assert_eq!(ledger_service.epoch_for_height(epoch_length - 1), 0);
+        assert_eq!(ledger_service.epoch_for_height(epoch_length), 1);
+        assert_eq!(ledger_service.epoch_for_height(2 * epoch_length - 1), 1);
+        assert_eq!(ledger_service.epoch_for_height(2 * epoch_length), 2);
+
+        // Ensure the epoch challenge is cached for the latest epoch.
+        let epoch_challenge = ledger_service.latest_epoch_challenge().unwrap();
+        let (epoch_number, cached) = ledger_service.epoch_challenge_cache.read().clone().unwrap();
+        assert_eq!(epoch_number, 0);
+        assert_eq!(cached, epoch_challenge);
+        assert_eq!(epoch_challenge, ledger_service.ledger.latest_epoch_challenge().unwrap());
+    }
+
+    #[test]
+    fn test_check_state_transition() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure the genesis block has a valid state transition.
+        assert!(ledger_service.check_state_transition(&ledger_service.latest_block()).is_ok());
+
+        // Ensure a mutated root is rejected.
+        let rng = &mut TestRng::default();
+        let root = Field::<CurrentNetwork>::rand(rng);
+        assert!(ensure_root_matches("transactions root", 1, root, root).is_ok());
+        assert!(ensure_root_matches("transactions root", 1, root, Field::rand(rng)).is_err());
+
+        // Ensure the committed blocks of an advanced ledger have valid state transitions.
+        let (ledger_service, private_keys) = sample_advanced_ledger_service(1, rng);
+        for height in 0..=ledger_service.latest_block_height() {
+            assert!(ledger_service.check_state_transition(&ledger_service.ledger.get_block(height).unwrap()).is_ok());
+        }
+        // Ensure a candidate for the next block has a valid state transition.
+        let block = sample_next_quorum_block(&ledger_service, &private_keys, rng);
+        assert!(ledger_service.check_state_transition(&block).is_ok());
+        // Ensure the candidate is rejected by a ledger with a different prior block, due to its previous state root.
+        let (other_ledger_service, _) = sample_advanced_ledger_service(1, rng);
+        assert!(other_ledger_service.check_state_transition(&block).is_err());
+    }
+
+    #[test]
+    fn test_earliest_available_height() {
+        // Ensure the earliest height is found after simulated pruning.
+        for pruned in [0, 1, 37, 99, 100] {
+            assert_eq!(find_earliest_height(100, |height| height >= pruned), pruned);
+        }
+        // Ensure the latest height is returned if no heights are present.
+        assert_eq!(find_earliest_height(100, |_| false), 100);
+        assert_eq!(find_earliest_height(0, |_| true), 0);
+
+        // Ensure the full history is available on an unpruned ledger.
+        let ledger_service = sample_ledger_service();
+        assert_eq!(ledger_service.earliest_available_height(), 0);
+    }
+
+    #[test]
+    fn test_estimate_sync_eta() {
+        // Initialize the ledger service, which is at the genesis block.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure there is no estimate when already synced.
+        assert_eq!(ledger_service.estimate_sync_eta(0, 10.0), None);
+        // Ensure there is no estimate when the rate is not positive.
+        assert_eq!(ledger_service.estimate_sync_eta(100, 0.0), None);
+        assert_eq!(ledger_service.estimate_sync_eta(100, -1.0), None);
+        assert_eq!(ledger_service.estimate_sync_eta(100, f64::NAN), None);
+        // Ensure the estimate is the remaining blocks over the rate.
+        assert_eq!(ledger_service.estimate_sync_eta(100, 10.0), Some(std::time::Duration::from_secs(10)));
+        assert_eq!(ledger_service.estimate_sync_eta(1, 2.0), Some(std::time::Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn test_check_solution_epoch() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Sample a solution for the latest epoch.
+        let solution = sample_solution(&ledger_service, rng);
+
+        // Ensure the solution is accepted for the next round.
+        assert!(ledger_service.check_solution_epoch(&solution, ledger_service.latest_round() + 1).is_ok());
+        // Ensure the solution is rejected if it is not valid for the epoch challenge.
+        let ledger_service = sample_ledger_service().with_solution_verifier(Arc::new(FixedVerifier(false)));
+        assert!(ledger_service.check_solution_epoch(&solution, ledger_service.latest_round() + 1).is_err());
+
+        // Advance the ledger to the last block of the first epoch, and sample a solution for it.
+        let num_blocks = <CurrentNetwork as Network>::NUM_BLOCKS_PER_EPOCH - 1;
+        let (ledger_service, _) = sample_advanced_ledger_service(num_blocks, rng);
+        let stale_solution = sample_solution(&ledger_service, rng);
+        // Ensure the solution is stale for the next round, which is included in the first block of the next epoch.
+        let round = ledger_service.latest_round() + 1;
+        assert_eq!(ledger_service.epoch_for_height(ledger_service.latest_block_height() + 1), 1);
+        let error = ledger_service.check_solution_epoch(&stale_solution, round).unwrap_err();
+        assert!(error.to_string().contains("stale epoch 0"));
+        // Ensure the solution is accepted for a round of its own epoch.
+        assert!(ledger_service.check_solution_epoch(&stale_solution, ledger_service.latest_round()).is_ok());
+        // Ensure a solution for the next epoch is accepted for the next round.
+        let epoch_challenge =
+            ledger_service.ledger.get_epoch_challenge(ledger_service.latest_block_height() + 1).unwrap();
+        let address = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let solution =
+            ledger_service.ledger.coinbase_puzzle().prove(&epoch_challenge, address, rng.gen(), None).unwrap();
+        assert!(ledger_service.check_solution_epoch(&solution, round).is_ok());
+    }
+
+    #[test]
+    fn test_voting_power() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee with a 1:1:2:4 stake split.
+        let stakes = [MIN_VALIDATOR_STAKE, MIN_VALIDATOR_STAKE, 2 * MIN_VALIDATOR_STAKE, 4 * MIN_VALIDATOR_STAKE];
+        let committee = sample_committee(0, &stakes, rng);
+        let members = committee.members().keys().copied().collect::<Vec<_>>();
+        // Ensure the voting power reflects the stake split.
+        assert_eq!(stake_fraction(&committee, [members[0]]), 0.125);
+        assert_eq!(stake_fraction(&committee, [members[1]]), 0.125);
+        assert_eq!(stake_fraction(&committee, [members[2]]), 0.25);
+        assert_eq!(stake_fraction(&committee, [members[3]]), 0.5);
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let committee = ledger_service.current_committee().unwrap();
+        // Ensure the voting power of the committee members sums to one.
+        let total =
+            committee.members().keys().map(|address| ledger_service.voting_power(address).unwrap()).sum::<f64>();
+        assert!((total - 1.0).abs() < 1e-9);
+        // Ensure the voting power for the next round matches the current committee.
+        for address in committee.members().keys() {
+            let expected = committee.get_stake(*address) as f64 / committee.total_stake() as f64;
+            assert_eq!(
+                ledger_service.voting_power_for_round(address, ledger_service.latest_round() + 1).unwrap(),
+                expected
+            );
+        }
+        // Ensure a non-member has no voting power.
+        let outsider = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert_eq!(ledger_service.voting_power(&outsider).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_find_missing_heights() {
+        // Ensure both gaps are found in a range with two gaps.
+        let stored = [10, 11, 13, 14, 17, 18, 19];
+        assert_eq!(missing_heights(10..20, |height| stored.contains(&height)), vec![12, 15, 16]);
+        // Ensure no heights are missing from a complete range.
+        assert!(missing_heights(10..12, |height| stored.contains(&height)).is_empty());
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure the heights beyond the latest block are missing.
+        assert_eq!(ledger_service.find_missing_heights(0..3).unwrap(), vec![1, 2]);
+        assert!(ledger_service.find_missing_heights(0..1).unwrap().is_empty());
+        // Ensure an unbounded range is rejected.
+        assert!(ledger_service.find_missing_heights(0..MAX_BLOCK_RANGE + 1).is_err());
+
+        // Ensure the query is skipped if the node is shutting down.
+        ledger_service.shutdown.store(true, Ordering::Relaxed);
+        assert!(ledger_service.find_missing_heights(0..3).is_err());
+    }
+
+    #[test]
+    fn test_check_round_advance() {
+        // Ensure a round that advances past the latest round is accepted.
+        assert!(ensure_round_advance(10, 11, 4).is_ok());
+        assert!(ensure_round_advance(10, 10 + 4 * MAX_ROUND_ADVANCE_PER_MEMBER, 4).is_ok());
+        // Ensure a round below or at the latest round is rejected.
+        assert!(ensure_round_advance(10, 9, 4).is_err());
+        assert!(ensure_round_advance(10, 10, 4).is_err());
+        // Ensure an implausibly large round is rejected.
+        assert!(ensure_round_advance(10, 11 + 4 * MAX_ROUND_ADVANCE_PER_MEMBER, 4).is_err());
+        assert!(ensure_round_advance(10, u64::MAX, 4).is_err());
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure a block that does not advance past the latest round is rejected.
+        assert!(ledger_service.check_round_advance(&ledger_service.latest_block()).is_err());
+    }
+
+    #[test]
+    fn test_epoch_participation_report() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let committee = ledger_service.current_committee().unwrap();
+
+        // Ensure the report for the genesis epoch covers each committee member.
+        let report = ledger_service.epoch_participation_report(0).unwrap();
+        assert_eq!(report.len(), committee.num_members());
+        for address in committee.members().keys() {
+            // Ensure the genesis block, which is not a quorum block, does not count towards participation.
+            assert_eq!(report[address], ParticipationStats::default());
+        }
+        // Ensure an epoch that has not started is rejected.
+        assert!(ledger_service.epoch_participation_report(1).is_err());
+
+        // Ensure the report is skipped if the node is shutting down.
+        ledger_service.shutdown.store(true, Ordering::Relaxed);
+        assert!(ledger_service.epoch_participation_report(0).is_err());
+
+        // Ensure the report for an epoch with quorum blocks counts the participation of each validator.
+        let rng = &mut TestRng::default();
+        let (ledger_service, _) = sample_advanced_ledger_service(2, rng);
+        let num_blocks = ledger_service.latest_block_height() as u64;
+        let report = ledger_service.epoch_participation_report(0).unwrap();
+        // Ensure each block was led by one validator.
+        assert_eq!(report.values().map(|stats| stats.blocks_led).sum::<u64>(), num_blocks);
+        for (address, stats) in &report {
+            // Ensure each validator authored a certificate in each block, and the leader also authored the anchor.
+            assert_eq!(stats.rounds_authored, num_blocks + stats.blocks_led);
+            // Ensure each validator earned a share of the block rewards.
+            assert!(stats.rewards_earned > 0);
+            let expected_rewards = (1..=ledger_service.latest_block_height())
+                .map(|height| ledger_service.validator_reward_share(address, height).unwrap())
+                .sum::<u64>();
+            assert_eq!(stats.rewards_earned, expected_rewards);
+        }
+    }
+
+    #[test]
+    fn test_get_transaction_certificate() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure the confirmed transactions in the genesis block, which is not a quorum block, have no certificate.
+        for transaction_id in ledger_service.latest_block().transaction_ids() {
+            assert!(ledger_service.get_transaction_certificate(*transaction_id).unwrap().is_none());
+        }
+        // Ensure an unconfirmed transaction has no certificate.
+        let transaction =
+            snarkvm::ledger::block::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        assert!(ledger_service.get_transaction_certificate(transaction.id()).unwrap().is_none());
+
+        // Ensure a transaction confirmed in a quorum block resolves to the anchor certificate that carried it.
+        let (ledger_service, _) = sample_unbonding_ledger_service(MIN_VALIDATOR_STAKE / 4, rng);
+        let block = ledger_service.latest_block();
+        let Authority::Quorum(subdag) = block.authority() else { panic!("Expected a quorum block") };
+        let transaction_ids = block.transaction_ids().copied().collect::<Vec<_>>();
+        assert!(!transaction_ids.is_empty());
+        for transaction_id in transaction_ids {
+            let certificate = ledger_service.get_transaction_certificate(transaction_id).unwrap().unwrap();
+            assert_eq!(certificate, *subdag.leader_certificate());
+            assert!(certificate.transmission_ids().contains(&TransmissionID::Transaction(transaction_id)));
+        }
+    }
+
+    #[test]
+    fn test_verify_committee_integrity() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee.
+        let committee = sample_committee(0, &[MIN_VALIDATOR_STAKE; 4], rng);
+        let mut members = committee.members().clone();
+
+        // Ensure the committee is consistent.
+        assert!(ensure_consistent_stake(&members, 4 * MIN_VALIDATOR_STAKE).is_ok());
+        // Ensure an inconsistent total stake is rejected.
+        assert!(ensure_consistent_stake(&members, 4 * MIN_VALIDATOR_STAKE + 1).is_err());
+        assert!(ensure_consistent_stake(&members, 0).is_err());
+        // Ensure a member without stake is rejected.
+        members[0].0 = 0;
+        assert!(ensure_consistent_stake(&members, 3 * MIN_VALIDATOR_STAKE).is_err());
+
+        // Ensure the committees loaded from the ledger are consistent.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.verify_committee_integrity(&committee).is_ok());
+        assert!(ledger_service.verify_committee_integrity(&ledger_service.current_committee().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_epoch_height_range() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure consecutive epochs cover contiguous, non-overlapping block ranges.
+        let mut previous_range = ledger_service.epoch_height_range(0);
+        assert_eq!(previous_range, 0..CurrentNetwork::NUM_BLOCKS_PER_EPOCH);
+        for epoch in 1..10 {
+            let range = ledger_service.epoch_height_range(epoch);
+            assert_eq!(range.start, previous_range.end);
+            assert_eq!(range.len(), CurrentNetwork::NUM_BLOCKS_PER_EPOCH as usize);
+            previous_range = range;
+        }
+        // Ensure each height in the range maps back to the epoch.
+        for height in ledger_service.epoch_height_range(3) {
+            assert_eq!(ledger_service.epoch_for_height(height), 3);
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_hash() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let genesis_hash = ledger_service.latest_block().hash();
+
+        // Ensure the checkpoint hash of the genesis block is deterministic.
+        let checkpoint_hash = ledger_service.checkpoint_hash(0).unwrap();
+        assert_eq!(checkpoint_hash, accumulate_checkpoint_hash(Field::zero(), genesis_hash).unwrap());
+        assert_eq!(checkpoint_hash, ledger_service.checkpoint_hash(0).unwrap());
+        // Ensure the genesis checkpoint hash is cached.
+        assert_eq!(ledger_service.checkpoint_cache.read().get(&0), Some(&(genesis_hash, checkpoint_hash)));
+        // Ensure a missing block has no checkpoint hash.
+        assert!(ledger_service.checkpoint_hash(1).is_err());
+
+        // Ensure extending the range changes the checkpoint hash.
+        let block_hash = <CurrentNetwork as Network>::BlockHash::from(Field::rand(rng));
+        let extended_hash = accumulate_checkpoint_hash(checkpoint_hash, block_hash).unwrap();
+        assert_ne!(extended_hash, checkpoint_hash);
+        assert_eq!(extended_hash, accumulate_checkpoint_hash(checkpoint_hash, block_hash).unwrap());
+    }
+
+    #[test]
+    fn test_transmission_id_variant_matches() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transaction and a solution.
+        let transaction =
+            snarkvm::ledger::block::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transaction_id = TransmissionID::Transaction(transaction.id());
+        let transaction = Transmission::Transaction(Data::Object(transaction));
+        let solution = sample_solution(&sample_ledger_service(), rng);
+        let solution_id = TransmissionID::Solution(solution.commitment());
+        let solution = Transmission::Solution(Data::Object(solution));
+
+        let ids = [TransmissionID::Ratification, solution_id, transaction_id];
+        let transmissions = [Transmission::Ratification, solution, transaction];
+        // Ensure only the matching combinations are accepted.
+        for (i, id) in ids.iter().enumerate() {
+            for (j, transmission) in transmissions.iter().enumerate() {
+                assert_eq!(CurrentLedgerService::transmission_id_variant_matches(id, transmission), i == j);
+            }
+        }
+
+        // Ensure a mismatching pair is rejected.
+        let ledger_service = sample_ledger_service();
+        let mut transmission = transmissions[1].clone();
+        assert!(ledger_service.ensure_transmission_id_matches(ids[2], &mut transmission).is_err());
+    }
+
+    #[test]
+    fn test_address_transaction_count() {
+        let rng = &mut TestRng::default();
+
+        // Sample two addresses.
+        let address = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let other = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+
+        // Ensure an address literal references only its own address.
+        let literal = Plaintext::from(Literal::Address(address));
+        assert!(plaintext_references(&literal, &address));
+        assert!(!plaintext_references(&literal, &other));
+        // Ensure an address nested in a struct is referenced.
+        let plaintext =
+            Plaintext::<CurrentNetwork>::from_str(&format!("{{ owner: {address}, amount: 1u64 }}")).unwrap();
+        assert!(plaintext_references(&plaintext, &address));
+        assert!(!plaintext_references(&plaintext, &other));
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure an address without activity has no transactions.
+        assert_eq!(ledger_service.address_transaction_count(&other, 0..1).unwrap(), 0);
+        // Ensure an unbounded range is rejected.
+        assert!(ledger_service.address_transaction_count(&address, 0..MAX_BLOCK_RANGE + 1).is_err());
+
+        // Ensure the count is skipped if the node is shutting down.
+        ledger_service.shutdown.store(true, Ordering::Relaxed);
+        assert!(ledger_service.address_transaction_count(&address, 0..1).is_err());
+    }
+
+    #[test]
+    fn test_expected_block_reward() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let (ledger_service, _) = sample_advanced_ledger_service(3, rng);
+        let latest_height = ledger_service.latest_block_height();
+
+        // Ensure the block reward of each block, including those before the latest block, is the ratified reward.
+        for height in 1..=latest_height {
+            let block = ledger_service.ledger.get_block(height).unwrap();
+            let ratified_reward = block
+                .ratifications()
+                .iter()
+                .find_map(|ratify| match ratify {
+                    Ratify::BlockReward(amount) => Some(*amount),
+                    _ => None,
+                })
+                .unwrap();
+            let expected = ledger_service.expected_block_reward(height).unwrap();
+            assert_ne!(expected, 0);
+            assert_eq!(expected, ratified_reward);
+            // Ensure the block reward is computed from the starting supply, as the blocks have no solutions or fees.
+            assert_eq!(expected, block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME, 0, 0));
+        }
+        // Ensure the genesis block and a height after the latest block fail.
+        assert!(ledger_service.expected_block_reward(0).is_err());
+        assert!(ledger_service.expected_block_reward(latest_height + 1).is_err());
+
+        // Ensure a coinbase reward that disagrees with the ratified puzzle reward is reported.
+        let ratifications = [Ratify::<CurrentNetwork>::BlockReward(100), Ratify::PuzzleReward(0)];
+        assert!(ensure_rewards_match(100, puzzle_reward(80), &ratifications).is_err());
+        assert!(ensure_rewards_match(100, puzzle_reward(0), &ratifications).is_ok());
+    }
+
+    #[test]
+    fn test_check_leader_eligibility() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let round = ledger_service.latest_round() + 2;
+        // Compute the leader for the round.
+        let committee = ledger_service.get_previous_committee_for_round(round).unwrap();
+        let leader = committee.get_leader(round).unwrap();
+
+        // Ensure the elected leader is eligible.
+        assert!(ledger_service.check_leader_eligibility(round, &leader).is_ok());
+        // Ensure the other committee members are not eligible.
+        for address in committee.members().keys().filter(|address| **address != leader) {
+            assert!(ledger_service.check_leader_eligibility(round, address).is_err());
+        }
+        // Ensure an odd round has no eligible leader.
+        assert!(ledger_service.check_leader_eligibility(round + 1, &leader).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_verification_throughput() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service().with_solution_verifier(Arc::new(FixedVerifier(true)));
+        // Ensure there is no throughput before any verifications.
+        assert_eq!(ledger_service.verification_throughput(), ThroughputStats::default());
+
+        // Verify several solutions.
+        let solution = sample_solution(&ledger_service, rng);
+        for _ in 0..3 {
+            ledger_service.check_solution_basic(solution.commitment(), Data::Object(solution.clone())).await.unwrap();
+        }
+        // Ensure the solution throughput reflects the verifications.
+        let throughput = ledger_service.verification_throughput();
+        assert_eq!(throughput.solution_per_sec, 3.0 / THROUGHPUT_WINDOW.as_secs_f64());
+        assert_eq!(throughput.tx_per_sec, 0.0);
+
+        // Ensure verifications outside the throughput window are dropped.
+        let verifications = Mutex::new(VecDeque::new());
+        let now = Instant::now();
+        record_verification(&verifications, now);
+        assert!(verifications_per_sec(&verifications, now) > 0.0);
+        assert_eq!(verifications_per_sec(&verifications, now + THROUGHPUT_WINDOW * 2), 0.0);
+    }
+
+    #[test]
+    fn test_verify_subdag_authors() {
+        let rng = &mut TestRng::default();
+
+        // Sample the certificates for round 2.
+        let (_, certificates) =
+            snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate_with_previous_certificates(
+                3, rng,
+            );
+        let rounds = BTreeMap::from([(2, certificates.iter().cloned().collect::<IndexSet<_>>())]);
+        // Initialize a committee of the authors.
+        let authors = certificates.iter().map(|certificate| certificate.author()).collect();
+        let committee =
+            snarkvm::ledger::committee::test_helpers::sample_committee_for_round_and_members(2, authors, rng);
+        // Initialize a committee that excludes the authors.
+        let other_committee = snarkvm::ledger::committee::test_helpers::sample_committee_for_round(2, rng);
+
+        // Ensure certificates from committee members are accepted.
+        assert!(ensure_committee_authors(&rounds, |_| Ok(committee.clone())).is_ok());
+        // Ensure certificates from non-members are rejected.
+        assert!(ensure_committee_authors(&rounds, |_| Ok(other_committee.clone())).is_err());
+        // Ensure a missing committee is rejected.
+        assert!(ensure_committee_authors(&rounds, |round| bail!("No committee for round {round}")).is_err());
+    }
+
+    #[test]
+    fn test_get_withdrawal_address() {
+        let rng = &mut TestRng::default();
+
+        // Ensure an address value is parsed.
+        let address = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert_eq!(value_to_address(&Value::from(Literal::Address(address))).unwrap(), address);
+        // Ensure a non-address value is rejected.
+        assert!(value_to_address(&Value::<CurrentNetwork>::from_str("1u64").unwrap()).is_err());
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure the genesis validators have not set a withdrawal address.
+        for validator in ledger_service.current_committee().unwrap().members().keys() {
+            assert_eq!(ledger_service.get_withdrawal_address(validator).unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn test_check_fee_sufficient() {
+        // Ensure a fee that covers the cost is accepted.
+        assert!(ensure_fee_covers_cost(100, 100).is_ok());
+        assert!(ensure_fee_covers_cost(101, 100).is_ok());
+        // Ensure an underfunded fee is rejected, with the shortfall.
+        let error = ensure_fee_covers_cost(60, 100).unwrap_err();
+        assert!(error.to_string().contains("by 40"));
+        assert!(ensure_fee_covers_cost(0, 1).is_err());
+    }
+
+    #[test]
+    fn test_finalize_snapshot() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let path = std::env::temp_dir().join(format!("finalize-snapshot-{}", TestRng::default().gen::<u64>()));
+
+        // Export and import the snapshot.
+        ledger_service.export_finalize_snapshot(0, &path).unwrap();
+        let snapshot = ledger_service.import_finalize_snapshot(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // Ensure the snapshot matches the finalize store.
+        assert_eq!(snapshot.height, 0);
+        assert_eq!(snapshot.block_hash, ledger_service.latest_block().hash());
+        let (_, _, entries) = snapshot
+            .mappings
+            .iter()
+            .find(|(program_id, mapping_name, _)| {
+                program_id.to_string() == "credits.aleo" && mapping_name.to_string() == "account"
+            })
+            .unwrap();
+        assert_eq!(*entries, ledger_service.get_credits_mapping("account").unwrap());
+        // Ensure the snapshot round-trips through its byte encoding.
+        assert_eq!(FinalizeSnapshot::from_bytes_le(&snapshot.to_bytes_le().unwrap()).unwrap(), snapshot);
+
+        // Ensure a snapshot cannot be taken at a past or future height.
+        assert!(ledger_service.export_finalize_snapshot(1, &path).is_err());
+    }
+
+    #[test]
+    fn test_check_unique_transactions() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let block = ledger_service.latest_block();
+
+        // Ensure the genesis block has unique transactions.
+        assert!(ledger_service.check_unique_transactions(&block).is_ok());
+        // Ensure a duplicated transaction is rejected.
+        let transaction_id = block.transaction_ids().next().unwrap();
+        let transaction_ids = block.transaction_ids().chain([transaction_id]);
+        assert!(ensure_unique_transaction_ids::<CurrentNetwork>(transaction_ids).is_err());
+    }
+
+    #[test]
+    fn test_projected_committee_for_round() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let current_committee = ledger_service.current_committee().unwrap();
+
+        // Ensure the committee for the latest round is the committee in the ledger.
+        let round = ledger_service.latest_round();
+        assert_eq!(
+            ledger_service.projected_committee_for_round(round).unwrap(),
+            ledger_service.get_committee_for_round(round).unwrap()
+        );
+        // Ensure the projected committee for a future round is the current committee.
+        for round in [round + 1, round + 100] {
+            assert_eq!(ledger_service.projected_committee_for_round(round).unwrap(), current_committee);
+        }
+
+        // Initialize a ledger service in which a committee member has a pending unbond.
+        let rng = &mut TestRng::default();
+        let amount = MIN_VALIDATOR_STAKE / 4;
+        let (ledger_service, validator) = sample_unbonding_ledger_service(amount, rng);
+        assert_eq!(ledger_service.get_unbonding_amount(&validator).unwrap(), amount);
+        // Ensure the unbond was removed from the committee stake when it was finalized.
+        let block = ledger_service.latest_block();
+        let previous_stake =
+            ledger_service.get_previous_committee_for_round(block.round()).unwrap().get_stake(validator);
+        let current_committee = ledger_service.current_committee().unwrap();
+        let stake = current_committee.get_stake(validator);
+        assert!(stake < previous_stake);
+
+        // Ensure the pending unbond is not subtracted again, before or after it unlocks.
+        let round = ledger_service.latest_round();
+        for round in [round + 2, round + 20_000] {
+            let projected_committee = ledger_service.projected_committee_for_round(round).unwrap();
+            assert_eq!(projected_committee.get_stake(validator), stake);
+            assert_eq!(projected_committee, current_committee);
+        }
+    }
+
+    #[test]
+    fn test_verify_solution_epoch_challenge() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure a solution for the latest epoch challenge is accepted.
+        let solution = sample_solution(&ledger_service, rng);
+        assert!(ledger_service.verify_solution_epoch_challenge(&solution).is_ok());
+
+        // Sample a solution for the wrong epoch challenge.
+        let latest_epoch_challenge = ledger_service.latest_epoch_challenge().unwrap();
+        let epoch_challenge = EpochChallenge::new(
+            latest_epoch_challenge.epoch_number() + 1,
+            <CurrentNetwork as Network>::BlockHash::from(Field::rand(rng)),
+            latest_epoch_challenge.degree(),
+        )
+        .unwrap();
+        let address = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let solution =
+            ledger_service.ledger.coinbase_puzzle().prove(&epoch_challenge, address, rng.gen(), None).unwrap();
+        // Ensure the solution is rejected.
+        assert!(ledger_service.verify_solution_epoch_challenge(&solution).is_err());
+    }
+
+    #[test]
+    fn test_min_certificates_for_quorum() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee with skewed stake.
+        let stakes = [
+            5 * MIN_VALIDATOR_STAKE,
+            MIN_VALIDATOR_STAKE,
+            MIN_VALIDATOR_STAKE,
+            MIN_VALIDATOR_STAKE,
+            MIN_VALIDATOR_STAKE,
+        ];
+        let committee = sample_committee(0, &stakes, rng);
+        // Ensure the largest validator and two others are needed to reach quorum.
+        let stakes = committee.members().values().map(|(stake, _)| *stake);
+        assert_eq!(min_stakes_for_threshold(stakes, committee.quorum_threshold()), Some(3));
+
+        // Ensure an unreachable threshold is reported.
+        assert_eq!(min_stakes_for_threshold([1, 2, 3], 7), None);
+        assert_eq!(min_stakes_for_threshold([1, 2, 3], 6), Some(3));
+        assert_eq!(min_stakes_for_threshold([1, 5, 3], 5), Some(1));
+
+        // Ensure an equal-stake committee needs more than two thirds of its validators.
+        let ledger_service = sample_ledger_service();
+        let committee = ledger_service.current_committee().unwrap();
+        let count = ledger_service.min_certificates_for_quorum(ledger_service.latest_round() + 2).unwrap();
+        assert!(count * 3 > committee.num_members() * 2);
+    }
+
+    #[test]
+    fn test_validator_transmissions_in_round() {
+        let rng = &mut TestRng::default();
+
+        // Sample the certificates of a round.
+        let (_, certificates) =
+            snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate_with_previous_certificates(
+                3, rng,
+            );
+        // Ensure each author's transmission IDs are those of their certificate.
+        for certificate in &certificates {
+            let expected = certificate.transmission_ids().iter().copied().collect::<Vec<_>>();
+            assert_eq!(author_transmission_ids(&certificates, &certificate.author()), expected);
+        }
+        // Ensure an address without a certificate has no transmission IDs.
+        let outsider = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert!(author_transmission_ids(&certificates, &outsider).is_empty());
+
+        // Ensure a round without committed certificates has no transmission IDs.
+        let ledger_service = sample_ledger_service();
+        let author = certificates[0].author();
+        assert!(ledger_service
+            .validator_transmissions_in_round(&author, ledger_service.latest_round() + 2)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_verify_genesis_block() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let genesis = ledger_service.get_block(0).unwrap();
+
+        // Ensure the genesis block is well-formed.
+        assert!(ledger_service.verify_genesis_block().is_ok());
+
+        // Ensure a genesis block at a non-zero height or round is rejected.
+        let ratifications = genesis.ratifications().iter().collect::<Vec<_>>();
+        assert!(ensure_genesis_parts(1, 0, true, ratifications.clone()).is_err());
+        assert!(ensure_genesis_parts(0, 1, true, ratifications.clone()).is_err());
+        // Ensure a genesis block without a beacon authority is rejected.
+        assert!(ensure_genesis_parts(0, 0, false, ratifications.clone()).is_err());
+        // Ensure a genesis block without exactly one genesis ratification is rejected.
+        assert!(ensure_genesis_parts::<CurrentNetwork>(0, 0, true, []).is_err());
+        assert!(ensure_genesis_parts(0, 0, true, ratifications.iter().chain(&ratifications).copied()).is_err());
+    }
+
+    #[test]
+    fn test_get_block_certificate_ids() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure each certificate ID in the block is in the ledger.
+        for certificate_id in ledger_service.get_block_certificate_ids(0).unwrap() {
+            assert!(ledger_service.contains_certificate(&certificate_id).unwrap());
+        }
+        // Ensure the genesis block, which is not a quorum block, has no certificates.
+        assert!(ledger_service.get_block_certificate_ids(0).unwrap().is_empty());
+        // Ensure a missing block is rejected.
+        assert!(ledger_service.get_block_certificate_ids(1).is_err());
+
+        // Ensure each certificate ID in a quorum block is in the ledger.
+        let rng = &mut TestRng::default();
+        let (ledger_service, private_keys) = sample_advanced_ledger_service(2, rng);
+        for height in 1..=ledger_service.latest_block_height() {
+            let certificate_ids = ledger_service.get_block_certificate_ids(height).unwrap();
+            // Ensure the block commits a certificate from each committee member, and the anchor certificate.
+            assert_eq!(certificate_ids.len(), private_keys.len() + 1);
+            for certificate_id in certificate_ids {
+                assert!(ledger_service.contains_certificate(&certificate_id).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_quorum_concentration() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee where one validator holds a majority.
+        let stakes = [10 * MIN_VALIDATOR_STAKE, MIN_VALIDATOR_STAKE, MIN_VALIDATOR_STAKE, MIN_VALIDATOR_STAKE];
+        let committee = sample_committee(0, &stakes, rng);
+        // Ensure the majority validator alone reaches quorum.
+        assert_eq!(quorum_concentration_of(&committee).unwrap(), 10.0 / 13.0);
+        assert_eq!(largest_share_of(&committee), 10.0 / 13.0);
+
+        // Ensure the ledger reports the concentration of the current committee.
+        let ledger_service = sample_ledger_service();
+        let committee = ledger_service.current_committee().unwrap();
+        let concentration = ledger_service.quorum_concentration().unwrap();
+        assert_eq!(concentration, quorum_concentration_of(&committee).unwrap());
+        assert!(concentration > 2.0 / 3.0 && concentration <= 1.0);
+        assert!(ledger_service.largest_validator_share().unwrap() <= concentration);
+    }
+
+    #[test]
+    fn test_check_deployment_unique() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let credits = ProgramID::from_str("credits.aleo").unwrap();
+        let is_deployed = |program_id: &ProgramID<CurrentNetwork>| {
+            ledger_service.ledger.vm().process().read().contains_program(program_id)
+        };
+
+        // Ensure redeploying an existing program is rejected.
+        let error = ensure_program_not_deployed(&credits, is_deployed).unwrap_err();
+        assert!(error.to_string().contains("already deployed"));
+        // Ensure deploying a new program is accepted.
+        let program_id = ProgramID::from_str("new_program.aleo").unwrap();
+        assert!(ensure_program_not_deployed(&program_id, is_deployed).is_ok());
+
+        // Ensure a non-deployment transaction is accepted.
+        let transaction =
+            snarkvm::ledger::block::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        assert!(ledger_service.check_deployment_unique(&transaction).is_ok());
+    }
+
+    #[test]
+    fn test_epoch_progress() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let epoch_length = CurrentNetwork::NUM_BLOCKS_PER_EPOCH;
+
+        // Ensure the genesis block is at the start of the first epoch.
+        assert_eq!(ledger_service.epoch_progress().unwrap(), (0, epoch_length));
+        // Ensure the progress at a mid-epoch height is halfway through the epoch.
+        let height = 3 * epoch_length + epoch_length / 2;
+        assert_eq!(ledger_service.epoch_progress_at(height).unwrap(), (epoch_length / 2, epoch_length));
+        // Ensure the progress at the last height of an epoch is one block short of its length.
+        assert_eq!(ledger_service.epoch_progress_at(epoch_length - 1).unwrap(), (epoch_length - 1, epoch_length));
+    }
+
+    #[test]
+    fn test_check_block_programs_available() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let block = ledger_service.latest_block();
+
+        // Ensure the programs called in the genesis block are available.
+        assert!(ledger_service.check_block_programs_available(&block).is_ok());
+        // Ensure a block calling an undeployed program is rejected.
+        assert!(block.transitions().next().is_some());
+        assert!(ensure_functions_available(block.transitions(), |_, _| false).is_err());
+    }
+
+    #[test]
+    fn test_rolling_digest() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let genesis_hash = ledger_service.latest_block().hash();
+
+        // Ensure the digest of the genesis block matches its accumulated hash.
+        let digest = accumulate_checkpoint_hash(Field::zero(), genesis_hash).unwrap();
+        assert_eq!(ledger_service.rolling_digest(1).unwrap(), digest);
+        // Ensure a window beyond the chain covers the full chain.
+        assert_eq!(ledger_service.rolling_digest(10).unwrap(), digest);
+        // Ensure an empty window is rejected.
+        assert!(ledger_service.rolling_digest(0).is_err());
+
+        // Ensure the digest changes when the window shifts.
+        let hashes = (0..3).map(|_| <CurrentNetwork as Network>::BlockHash::from(Field::rand(rng))).collect::<Vec<_>>();
+        let digest_of = |hashes: &[<CurrentNetwork as Network>::BlockHash]| {
+            hashes.iter().try_fold(Field::zero(), |digest, hash| accumulate_checkpoint_hash(digest, *hash)).unwrap()
+        };
+        assert_ne!(digest_of(&hashes[0..2]), digest_of(&hashes[1..3]));
+    }
+
+    #[test]
+    fn test_verify_certificate_id() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Sample a certificate.
+        let certificate = snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate(rng);
+        let batch_header = certificate.batch_header();
+
+        // Ensure the certificate ID matches its batch header.
+        assert!(ledger_service.verify_certificate_id(&certificate).is_ok());
+        assert!(ensure_certificate_id(certificate.id(), batch_header).is_ok());
+        // Ensure a tampered certificate ID is rejected.
+        assert!(ensure_certificate_id(Field::<CurrentNetwork>::rand(rng), batch_header).is_err());
+    }
+
+    #[test]
+    fn test_get_previous_block_hash() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure the previous block hash matches the block hash of the preceding height.
+        for height in 1..=ledger_service.latest_block_height() {
+            let previous_hash = ledger_service.get_previous_block_hash(height).unwrap();
+            assert_eq!(previous_hash, ledger_service.get_block_hash(height - 1).unwrap());
+        }
+        // Ensure the previous block hash matches the one in the genesis block.
+        let genesis = ledger_service.get_block(0).unwrap();
+        assert_eq!(ledger_service.get_previous_block_hash(0).unwrap(), genesis.previous_hash());
+        // Ensure a missing block is rejected.
+        assert!(ledger_service.get_previous_block_hash(1).is_err());
+    }
+
+    #[test]
+    fn test_recent_tps() {
+        // Ensure the transactions per second reflect a synthetic chain of 3 blocks, 10 seconds apart,
+        // with 3, 5, and 2 transactions.
+        assert_eq!(transactions_per_second(3 + 5 + 2, 20), 0.5);
+        // Ensure a window without a time span is handled.
+        assert_eq!(transactions_per_second(3, 0), 0.0);
+        assert_eq!(transactions_per_second(3, -5), 0.0);
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure the transaction count matches the genesis block.
+        let count = ledger_service.get_block_transaction_count(0).unwrap();
+        assert_eq!(count, ledger_service.latest_block().transactions().len());
+        // Ensure a single-block window is handled.
+        assert_eq!(ledger_service.recent_tps(1).unwrap(), 0.0);
+        assert_eq!(ledger_service.recent_tps(10).unwrap(), 0.0);
+        // Ensure an empty window is rejected.
+        assert!(ledger_service.recent_tps(0).is_err());
+    }
+
+    #[test]
+    fn test_verify_solution_with_key() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service, with a verifier that rejects every solution.
+        let ledger_service = sample_ledger_service().with_solution_verifier(Arc::new(FixedVerifier(false)));
+        // Sample a solution.
+        let solution = sample_solution(&ledger_service, rng);
+        let epoch_challenge = ledger_service.latest_epoch_challenge().unwrap();
+        let key = ledger_service.ledger.coinbase_puzzle().coinbase_verifying_key().clone();
+
+        // Ensure the solution is verified under the provided key, rather than the configured verifier.
+        assert!(ledger_service.verify_solution_with_key(&solution, &key, &epoch_challenge, 0).unwrap());
+        // Ensure the solution is not valid for an unreachable proof target.
+        assert!(!matches!(
+            ledger_service.verify_solution_with_key(&solution, &key, &epoch_challenge, u64::MAX),
+            Ok(true)
+        ));
+    }
+
+    #[test]
+    fn test_certificate_timestamp_skew() {
+        let rng = &mut TestRng::default();
+
+        // Sample the certificates of a round.
+        let (_, certificates) =
+            snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate_with_previous_certificates(
+                3, rng,
+            );
+        // Set the block timestamp to 10 seconds after the first certificate.
+        let block_timestamp = certificates[0].timestamp() + 10;
+
+        // Ensure each skew is the offset of the certificate timestamp from the block timestamp.
+        let skews = timestamp_skews(&certificates, block_timestamp);
+        assert_eq!(skews.len(), certificates.len());
+        assert_eq!(skews[0], (certificates[0].author(), -10));
+        for (certificate, (author, skew)) in certificates.iter().zip(skews) {
+            assert_eq!(author, certificate.author());
+            assert_eq!(skew, certificate.timestamp() - block_timestamp);
+        }
+
+        // Ensure the genesis block, which is not a quorum block, has no certificates.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.certificate_timestamp_skew(0).unwrap().is_empty());
+
+        // Ensure the skews of a quorum block are the offsets of its certificate timestamps from the block timestamp.
+        let (ledger_service, _) = sample_advanced_ledger_service(1, rng);
+        let block = ledger_service.latest_block();
+        let Authority::Quorum(subdag) = block.authority() else { panic!("Expected a quorum block") };
+        let skews = ledger_service.certificate_timestamp_skew(block.height()).unwrap();
+        assert_eq!(skews.len(), subdag.values().flatten().count());
+        for (certificate, (author, skew)) in subdag.values().flatten().zip(skews) {
+            assert_eq!(author, certificate.author());
+            assert_eq!(skew, certificate.timestamp() - block.timestamp());
+        }
+    }
+
+    #[test]
+    fn test_validator_uptime() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a validator that missed half the rounds has half uptime.
+        assert_eq!(uptime_over_rounds(0..10, |round| Ok(round % 2 == 0)).unwrap(), 0.5);
+        assert_eq!(uptime_over_rounds(4..8, |_| Ok(true)).unwrap(), 1.0);
+        // Ensure an empty range is rejected.
+        assert!(uptime_over_rounds(4..4, |_| Ok(true)).is_err());
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let address = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        // Ensure a validator without committed certificates has no uptime.
+        let round = ledger_service.latest_round() + 1;
+        assert_eq!(ledger_service.validator_uptime(&address, round..round + 4).unwrap(), 0.0);
+        // Ensure an unbounded range is rejected.
+        assert!(ledger_service.validator_uptime(&address, 0..MAX_ROUND_RANGE + 1).is_err());
+
+        // Ensure the scan is skipped if the node is shutting down.
+        ledger_service.shutdown.store(true, Ordering::Relaxed);
+        assert!(ledger_service.validator_uptime(&address, round..round + 4).is_err());
+    }
+
+    #[test]
+    fn test_check_ratification_consistency() {
+        let rng = &mut TestRng::default();
+
+        // Ensure matching rewards are accepted.
+        let ratifications = [Ratify::<CurrentNetwork>::BlockReward(100), Ratify::PuzzleReward(40)];
+        assert!(ensure_rewards_match(100, 40, &ratifications).is_ok());
+        // Ensure an inflated block reward is rejected.
+        let ratifications = [Ratify::<CurrentNetwork>::BlockReward(101), Ratify::PuzzleReward(40)];
+        assert!(ensure_rewards_match(100, 40, &ratifications).is_err());
+        // Ensure an inflated puzzle reward is rejected.
+        let ratifications = [Ratify::<CurrentNetwork>::BlockReward(100), Ratify::PuzzleReward(41)];
+        assert!(ensure_rewards_match(100, 40, &ratifications).is_err());
+        // Ensure missing or repeated rewards are rejected.
+        assert!(ensure_rewards_match::<CurrentNetwork>(100, 40, &[]).is_err());
+        let ratifications =
+            [Ratify::<CurrentNetwork>::BlockReward(100), Ratify::BlockReward(100), Ratify::PuzzleReward(40)];
+        assert!(ensure_rewards_match(100, 40, &ratifications).is_err());
+
+        // Ensure the genesis block is rejected.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.check_ratification_consistency(&ledger_service.latest_block()).is_err());
+
+        // Ensure a candidate for the next block is accepted.
+        let (ledger_service, private_keys) = sample_advanced_ledger_service(1, rng);
+        let block = sample_next_quorum_block(&ledger_service, &private_keys, rng);
+        assert!(block
+            .ratifications()
+            .iter()
+            .any(|ratify| matches!(ratify, Ratify::BlockReward(amount) if *amount > 0)));
+        assert!(ledger_service.check_ratification_consistency(&block).is_ok());
+
+        // Ensure the committed blocks are accepted, including those before the latest block.
+        ledger_service.ledger.advance_to_next_block(&block).unwrap();
+        let block = sample_next_quorum_block(&ledger_service, &private_keys, rng);
+        ledger_service.ledger.advance_to_next_block(&block).unwrap();
+        for height in 1..=ledger_service.latest_block_height() {
+            let block = ledger_service.ledger.get_block(height).unwrap();
+            assert!(ledger_service.check_ratification_consistency(&block).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_address_transactions_paged() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a synthetic history, with the number of transactions at each height.
+        let history = [1, 0, 2, 0, 0, 1, 3];
+        let items_at = |height: u32| Ok((0..history[height as usize]).collect::<Vec<_>>());
+
+        // Page through the history.
+        let (page, cursor) = page_backward(7, 2, items_at).unwrap();
+        assert_eq!(page, vec![(6, 0), (6, 1), (6, 2)]);
+        assert_eq!(cursor, Some(6));
+        let (page, cursor) = page_backward(6, 2, items_at).unwrap();
+        assert_eq!(page, vec![(5, 0)]);
+        assert_eq!(cursor, Some(3));
+        let (page, cursor) = page_backward(3, 2, items_at).unwrap();
+        assert_eq!(page, vec![(2, 0), (2, 1)]);
+        assert_eq!(cursor, Some(2));
+        let (page, cursor) = page_backward(2, 2, items_at).unwrap();
+        assert_eq!(page, vec![(0, 0)]);
+        assert_eq!(cursor, None);
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let address = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        // Ensure an address without activity has an empty history.
+        assert_eq!(ledger_service.address_transactions_paged(&address, u32::MAX, 10).unwrap(), (vec![], None));
+        // Ensure an empty page is rejected.
+        assert!(ledger_service.address_transactions_paged(&address, u32::MAX, 0).is_err());
+
+        // Ensure the scan is skipped if the node is shutting down.
+        ledger_service.shutdown.store(true, Ordering::Relaxed);
+        assert!(ledger_service.address_transactions_paged(&address, u32::MAX, 10).is_err());
+    }
+
+    #[test]
+    fn test_verify_anchor_path() {
+        let rng = &mut TestRng::default();
+
+        // Sample an anchor certificate with previous certificates.
+        let (anchor, previous_certificates) =
+            snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate_with_previous_certificates(
+                3, rng,
+            );
+        let rounds = BTreeMap::from([
+            (2, previous_certificates.iter().cloned().collect::<IndexSet<_>>()),
+            (3, IndexSet::from([anchor.clone()])),
+        ]);
+
+        // Ensure the anchor is linked to each of its previous certificates.
+        for certificate in &previous_certificates {
+            assert!(ensure_linked(&anchor, &rounds, certificate.id()).is_ok());
+        }
+        // Ensure an anchor disconnected from the prior anchor is rejected.
+        assert!(ensure_linked(&anchor, &rounds, Field::rand(rng)).is_err());
+        assert!(ensure_linked(&previous_certificates[0], &rounds, anchor.id()).is_err());
+    }
+
+    #[test]
+    fn test_effective_stake() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let committee = ledger_service.current_committee().unwrap();
+
+        // Ensure the effective stake of each validator is its committee stake, as none are unbonding.
+        for address in committee.members().keys() {
+            assert_eq!(ledger_service.get_unbonding_amount(address).unwrap(), 0);
+            assert_eq!(ledger_service.effective_stake(address).unwrap(), committee.get_stake(*address));
+        }
+        // Ensure a non-member has no effective stake.
+        let outsider = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert_eq!(ledger_service.effective_stake(&outsider).unwrap(), 0);
+
+        // Ensure the unbonding amount is read from the unbonding state.
+        let unbond_state = Value::<CurrentNetwork>::from_str("{ microcredits: 250u64, height: 10u32 }").unwrap();
+        assert_eq!(struct_member_to_u64(&unbond_state, "microcredits").unwrap(), 250);
+
+        // Partially unbond the stake of a validator.
+        let amount = MIN_VALIDATOR_STAKE / 4;
+        let (ledger_service, validator) = sample_unbonding_ledger_service(amount, rng);
+        assert_eq!(ledger_service.get_unbonding_amount(&validator).unwrap(), amount);
+
+        // Retrieve the committee stake of the validator, before and after the unbonding block.
+        let block = ledger_service.latest_block();
+        let previous_committee = ledger_service.get_previous_committee_for_round(block.round()).unwrap();
+        let previous_stake = previous_committee.get_stake(validator);
+        let stake = ledger_service.current_committee().unwrap().get_stake(validator);
+        // Retrieve the staking reward credited to the validator in the unbonding block.
+        let block_reward = block
+            .ratifications()
+            .iter()
+            .find_map(|ratify| match ratify {
+                Ratify::BlockReward(amount) => Some(*amount),
+                _ => None,
+            })
+            .unwrap();
+        let reward =
+            proportional_share(block_reward, previous_stake - amount, previous_committee.total_stake() - amount);
+
+        // Ensure the committee stake dropped by the unbonded amount, when the unbond was finalized.
+        assert_eq!(stake, previous_stake - amount + reward);
+        // Ensure the effective stake is the committee stake, as the unbonded amount is not counted twice.
+        assert_eq!(ledger_service.effective_stake(&validator).unwrap(), stake);
+    }
+
+    #[test]
+    fn test_check_transaction_state_refs() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let finalize_store = ledger_service.ledger.vm().finalize_store();
+        let contains_key = |program_id: &ProgramID<CurrentNetwork>,
+                            mapping_name: &Identifier<CurrentNetwork>,
+                            key: &Plaintext<CurrentNetwork>| {
+            finalize_store.contains_key_confirmed(program_id, mapping_name, key)
+        };
+
+        // Ensure a read of an existing key is accepted.
+        let credits = ProgramID::from_str("credits.aleo").unwrap();
+        let committee = Identifier::from_str("committee").unwrap();
+        let validator = *ledger_service.current_committee().unwrap().members().keys().next().unwrap();
+        let reads = [(credits, committee, Plaintext::from(Literal::Address(validator)))];
+        assert!(ensure_mapping_keys_exist(&reads, contains_key).is_ok());
+        // Ensure a read of a nonexistent key is rejected.
+        let outsider = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let reads = [(credits, committee, Plaintext::from(Literal::Address(outsider)))];
+        assert!(ensure_mapping_keys_exist(&reads, contains_key).is_err());
+
+        // Ensure the genesis transactions reference existing state.
+        for transaction in ledger_service.latest_block().transactions().iter() {
+            assert!(ledger_service.check_transaction_state_refs(transaction.transaction()).is_ok());
+        }
+
+        // Ensure the reads of `unbond_public` are all guarded, as it branches on whether the caller is a validator.
+        let process = ledger_service.ledger.vm().process();
+        let process = process.read();
+        let function =
+            process.get_program(credits).unwrap().get_function(&Identifier::from_str("unbond_public").unwrap());
+        let finalize = function.unwrap().finalize_logic().cloned().unwrap();
+        assert!(finalize.commands().iter().any(|command| matches!(command, Command::Get(..))));
+        assert!(!unconditional_commands(finalize.commands()).any(|command| matches!(command, Command::Get(..))));
+        drop(process);
+
+        // Initialize a ledger service in which a delegator bonds to the first committee member.
+        let (ledger_service, private_keys) = sample_advanced_ledger_service(1, rng);
+        let validator = Address::try_from(&private_keys[0]).unwrap();
+        let delegator_private_key = PrivateKey::new(rng).unwrap();
+        let delegator = Address::try_from(&delegator_private_key).unwrap();
+        let amount = format!("{MIN_DELEGATOR_STAKE}u64");
+        let transfer = sample_credits_execution(
+            &ledger_service,
+            &private_keys[0],
+            "transfer_public",
+            &[&delegator.to_string(), &amount],
+            rng,
+        );
+        let block = sample_next_quorum_block_with_transactions(&ledger_service, &private_keys, vec![transfer], rng);
+        ledger_service.ledger.advance_to_next_block(&block).unwrap();
+        let bond = sample_credits_execution(
+            &ledger_service,
+            &delegator_private_key,
+            "bond_public",
+            &[&validator.to_string(), &amount],
+            rng,
+        );
+        let block = sample_next_quorum_block_with_transactions(&ledger_service, &private_keys, vec![bond], rng);
+        ledger_service.ledger.advance_to_next_block(&block).unwrap();
+        assert_eq!(ledger_service.get_bonded_amount(&delegator).unwrap(), MIN_DELEGATOR_STAKE);
+        assert!(!ledger_service.current_committee().unwrap().is_committee_member(delegator));
+
+        // Ensure the unbond of the delegator is accepted, although it is not in the `committee` mapping.
+        let unbond =
+            sample_credits_execution(&ledger_service, &delegator_private_key, "unbond_public", &[&amount], rng);
+        assert!(ledger_service.check_transaction_state_refs(&unbond).is_ok());
+        // Ensure the unbond of the delegator is valid.
+        let block = sample_next_quorum_block_with_transactions(&ledger_service, &private_keys, vec![unbond], rng);
+        ledger_service.ledger.advance_to_next_block(&block).unwrap();
+        assert_eq!(ledger_service.get_unbonding_amount(&delegator).unwrap(), MIN_DELEGATOR_STAKE);
+    }
+
+    #[test]
+    fn test_average_rounds_per_block() {
+        // Ensure the average reflects a synthetic chain with round deltas of 2, 4, and 3.
+        assert_eq!(average_round_delta(&[10, 12, 16, 19]), Some(3.0));
+        assert_eq!(average_round_delta(&[10, 12]), Some(2.0));
+        // Ensure at least two blocks are required.
+        assert_eq!(average_round_delta(&[10]), None);
+        assert_eq!(average_round_delta(&[]), None);
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure the average requires at least two blocks.
+        assert!(ledger_service.average_rounds_per_block(0..1).is_err());
+        // Ensure the average rejects missing blocks.
+        assert!(ledger_service.average_rounds_per_block(0..2).is_err());
+        // Ensure the average rejects unbounded ranges.
+        assert!(ledger_service.average_rounds_per_block(0..MAX_BLOCK_RANGE + 1).is_err());
+    }
+
+    #[test]
+    fn test_check_solution_uniqueness() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Sample a solution.
+        let commitment = sample_solution(&ledger_service, rng).commitment();
+
+        // Ensure a new solution is accepted.
+        assert!(ensure_unique_solutions([&commitment], |_| Ok(false)).is_ok());
+        // Ensure a solution committed earlier in the epoch is rejected.
+        assert!(ensure_unique_solutions([&commitment], |committed| Ok(*committed == commitment)).is_err());
+        // Ensure a solution repeated within the block is rejected.
+        assert!(ensure_unique_solutions([&commitment, &commitment], |_| Ok(false)).is_err());
+
+        // Ensure the check uses the committed solutions.
+        assert!(!ledger_service.ledger.contains_puzzle_commitment(&commitment).unwrap());
+    }
+
+    #[test]
+    fn test_nakamoto_coefficient() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee with stakes of 4:2:1:1, which has a quorum threshold of 6.
+        let stakes = [4 * MIN_VALIDATOR_STAKE, 2 * MIN_VALIDATOR_STAKE, MIN_VALIDATOR_STAKE, MIN_VALIDATOR_STAKE];
+        let committee = sample_committee(0, &stakes, rng);
+        // Ensure the two largest validators reach quorum.
+        assert_eq!(min_members_for_quorum(&committee).unwrap(), 2);
+
+        // Sample a committee where one validator holds a majority.
+        let stakes = [10 * MIN_VALIDATOR_STAKE, MIN_VALIDATOR_STAKE, MIN_VALIDATOR_STAKE, MIN_VALIDATOR_STAKE];
+        let committee = sample_committee(0, &stakes, rng);
+        // Ensure the majority validator alone reaches quorum.
+        assert_eq!(min_members_for_quorum(&committee).unwrap(), 1);
+
+        // Ensure the ledger reports the coefficient of the current committee, which has equal stakes.
+        let ledger_service = sample_ledger_service();
+        let committee = ledger_service.current_committee().unwrap();
+        let expected = committee.num_members() * 2 / 3 + 1;
+        assert_eq!(ledger_service.nakamoto_coefficient().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_get_mapping_struct_field() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let committee = ledger_service.current_committee().unwrap();
+
+        let credits = ProgramID::from_str("credits.aleo").unwrap();
+        let bonded = Identifier::from_str("bonded").unwrap();
+        let microcredits = Identifier::from_str("microcredits").unwrap();
+        // Ensure the bonded amount of each validator is extracted from its bond state.
+        for (address, (stake, _)) in committee.members() {
+            let key = Plaintext::from(Literal::Address(*address));
+            let value = ledger_service.get_mapping_struct_field(&credits, &bonded, &key, &microcredits).unwrap();
+            assert_eq!(value_to_u64(&value.unwrap()).unwrap(), *stake);
+            // Ensure a missing field is handled.
+            let missing = Identifier::from_str("missing").unwrap();
+            assert!(ledger_service.get_mapping_struct_field(&credits, &bonded, &key, &missing).unwrap().is_none());
+        }
+        // Ensure a missing key is handled.
+        let outsider = Plaintext::from(Literal::Address(Address::try_from(PrivateKey::new(rng).unwrap()).unwrap()));
+        assert!(ledger_service
+            .get_mapping_struct_field(&credits, &bonded, &outsider, &microcredits)
+            .unwrap()
+            .is_none());
+
+        // Ensure a non-struct value has no fields.
+        let value = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+        assert!(struct_member(&value, &microcredits).is_none());
+    }
+
+    #[test]
+    fn test_check_block_transmissions_known() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let block = ledger_service.latest_block();
+        let transmission_ids =
+            block.transaction_ids().copied().map(TransmissionID::Transaction).collect::<HashSet<TransmissionID<_>>>();
+        assert!(!transmission_ids.is_empty());
+
+        // Ensure the block is accepted when all of its transmissions were seen.
+        assert!(ledger_service.check_block_transmissions_known(&block, &transmission_ids).is_ok());
+        // Ensure the block is rejected when its transmissions were not seen, as they were not confirmed earlier.
+        assert!(ledger_service.check_block_transmissions_known(&block, &HashSet::new()).is_err());
+
+        // Ensure the first unseen transmission is reported.
+        let unseen = TransmissionID::Solution(sample_solution(&ledger_service, rng).commitment());
+        let error = ensure_transmissions_known([unseen], |id| Ok(transmission_ids.contains(id))).unwrap_err();
+        assert!(error.to_string().contains(&unseen.to_string()));
+    }
+
+    #[test]
+    fn test_time_since_epoch_start() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let epoch_length = CurrentNetwork::NUM_BLOCKS_PER_EPOCH;
+
+        // Ensure the genesis block is at the start of the first epoch.
+        assert_eq!(ledger_service.time_since_epoch_start().unwrap(), 0);
+        assert_eq!(ledger_service.time_since_epoch_start_at(0).unwrap(), 0);
+        // Ensure a mid-epoch height is measured from the first block of its epoch, which is missing.
+        assert!(ledger_service.time_since_epoch_start_at(epoch_length + epoch_length / 2).is_err());
+        // Ensure a mid-epoch height in the first epoch is measured from the genesis block, and it is missing.
+        assert!(ledger_service.time_since_epoch_start_at(epoch_length / 2).is_err());
+    }
+
+    #[test]
+    fn test_absent_validators_in_round() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee of four validators.
+        let committee = sample_committee(0, &[MIN_VALIDATOR_STAKE; 4], rng);
+        let members = committee.members().keys().copied().collect::<Vec<_>>();
+
+        // Ensure the validator that did not author is reported as absent.
+        let authors = [members[0], members[2], members[3]];
+        assert_eq!(absent_members(&committee, authors), vec![members[1]]);
+        // Ensure non-member authors are ignored.
+        let outsider = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert_eq!(absent_members(&committee, [members[0], members[2], members[3], outsider]), vec![members[1]]);
+        // Ensure no validator is absent under full participation.
+        assert!(absent_members(&committee, members.clone()).is_empty());
+
+        // Ensure every validator is absent in a round without committed certificates.
+        let ledger_service = sample_ledger_service();
+        let round = ledger_service.latest_round() + 2;
+        let absent = ledger_service.absent_validators_in_round(round).unwrap();
+        assert_eq!(absent.len(), ledger_service.current_committee().unwrap().num_members());
+    }
+
+    #[test]
+    fn test_verify_certificate_round_consistency() {
+        let rng = &mut TestRng::default();
+
+        // Sample an anchor certificate with previous certificates.
+        let (anchor, previous_certificates) =
+            snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate_with_previous_certificates(
+                3, rng,
+            );
+
+        // Ensure the anchor round follows the round of its previous certificates.
+        assert!(ensure_certificate_round(&anchor, &previous_certificates).is_ok());
+        // Ensure a certificate whose round disagrees with its previous certificates is rejected.
+        assert!(ensure_certificate_round(&anchor, [&anchor]).is_err());
+        assert!(ensure_certificate_round(&previous_certificates[0], [&anchor]).is_err());
+
+        // Ensure previous certificates that are not in the ledger are not checked.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.verify_certificate_round_consistency(&anchor).is_ok());
+    }
+
+    #[test]
+    fn test_total_fees_collected() {
+        // Ensure the total reflects a synthetic chain of 3 blocks with fees of 3, 5, and 2 microcredits.
+        assert_eq!(sum_fees([3, 5, 2].map(Ok)).unwrap(), 10);
+        assert_eq!(sum_fees([]).unwrap(), 0);
+        // Ensure an overflowing total is rejected.
+        assert!(sum_fees([Ok(u64::MAX), Ok(1)]).is_err());
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure the total matches the fees of the genesis block.
+        let block = ledger_service.latest_block();
+        let expected = block.transactions().iter().map(|tx| *tx.transaction().fee_amount().unwrap()).sum::<u64>();
+        assert_eq!(ledger_service.total_fees_collected(0..1).unwrap(), expected);
+        assert_eq!(ledger_service.total_fees_collected(0..0).unwrap(), 0);
+        // Ensure missing blocks and unbounded ranges are rejected.
+        assert!(ledger_service.total_fees_collected(0..2).is_err());
+        assert!(ledger_service.total_fees_collected(0..MAX_BLOCK_RANGE + 1).is_err());
+
+        // Ensure the total is skipped if the node is shutting down.
+        ledger_service.shutdown.store(true, Ordering::Relaxed);
+        assert!(ledger_service.total_fees_collected(0..1).is_err());
+    }
+
+    #[test]
+    fn test_verify_bond_meets_minimum_at_latest() {
+        let rng = &mut TestRng::default();
+
+        // Sample a validator and a delegator.
+        let validator = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let delegator = Address::<CurrentNetwork>::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+
+        // Ensure a self-bond must meet the validator minimum.
+        assert!(ensure_bond_minimum(&validator, &validator, MIN_VALIDATOR_STAKE).is_ok());
+        assert!(ensure_bond_minimum(&validator, &validator, MIN_VALIDATOR_STAKE - 1).is_err());
+        // Ensure a delegation must meet the delegator minimum.
+        assert!(ensure_bond_minimum(&delegator, &validator, MIN_DELEGATOR_STAKE).is_ok());
+        assert!(ensure_bond_minimum(&delegator, &validator, MIN_DELEGATOR_STAKE - 1).is_err());
+
+        // Ensure the bonded amount of a committee member is its self-bond.
+        let ledger_service = sample_ledger_service();
+        let committee = ledger_service.current_committee().unwrap();
+        for address in committee.members().keys() {
+            let bonded = ledger_service.get_bonded_amount(address).unwrap();
+            assert!(bonded >= MIN_VALIDATOR_STAKE);
+            // Ensure a top-up below the minimum is rejected on its own, and accepted in total with the existing bond.
+            let top_up = 1;
+            assert!(ensure_bond_minimum(address, address, top_up).is_err());
+            assert!(ensure_bond_minimum(address, address, bonded + top_up).is_ok());
+        }
+        // Ensure an address without a bond has no bonded amount.
+        assert_eq!(ledger_service.get_bonded_amount(&delegator).unwrap(), 0);
+
+        // Ensure a transaction that does not bond is rejected.
+        let transaction =
+            snarkvm::ledger::block::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        assert!(ledger_service.verify_bond_meets_minimum_at_latest(&transaction).is_err());
+    }
+
+    #[test]
+    fn test_get_block_mapping_updates() {
+        let rng = &mut TestRng::default();
+
+        // Sample an `account` mapping with two entries.
+        let credits = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+        let account = Identifier::from_str("account").unwrap();
+        let entries = (0..2)
+            .map(|_| {
+                let address = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+                (Plaintext::from(Literal::Address(address)), Value::from_str("100u64").unwrap())
+            })
+            .collect::<Vec<_>>();
+        let mappings = [(credits, account, entries.clone())];
+
+        // Sample a block that updates the first account, and inserts a key that is no longer in the mapping.
+        let mapping_id = to_mapping_id(&credits, &account).unwrap();
+        let key_id = to_key_id(&credits, &account, &entries[0].0).unwrap();
+        let operations = [
+            FinalizeOperation::UpdateKeyValue(mapping_id, 0, key_id, Field::rand(rng)),
+            FinalizeOperation::InsertKeyValue(mapping_id, Field::rand(rng), Field::rand(rng)),
+            FinalizeOperation::RemoveKeyValue(mapping_id, 1),
+        ];
+        // Ensure only the updated account is resolved.
+        let updates = resolve_mapping_updates(&operations, &mappings).unwrap();
+        assert_eq!(updates, vec![(credits, account, entries[0].0.clone(), entries[0].1.clone())]);
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure the updates of the genesis block match the finalize store.
+        let finalize_store = ledger_service.ledger.vm().finalize_store();
+        for (program_id, mapping_name, key, value) in ledger_service.get_block_mapping_updates(0).unwrap() {
+            assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key).unwrap(), Some(value));
+        }
+        // Ensure a block other than the latest block is rejected.
+        assert!(ledger_service.get_block_mapping_updates(1).is_err());
+    }
+
+    #[test]
+    fn test_check_subdag_freshness() {
+        let rng = &mut TestRng::default();
+
+        // Sample an anchor certificate with previous certificates.
+        let (anchor, previous_certificates) =
+            snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate_with_previous_certificates(
+                3, rng,
+            );
+        let rounds = BTreeMap::from([
+            (2, previous_certificates.iter().cloned().collect::<IndexSet<_>>()),
+            (3, IndexSet::from([anchor])),
+        ]);
+
+        // Ensure a subdag within the GC depth of the anchor round is accepted.
+        assert!(ensure_rounds_within_gc(&rounds, 3).is_ok());
+        assert!(ensure_rounds_within_gc(&rounds, 2 + MAX_COMMIT_DELAY_IN_ROUNDS).is_ok());
+        // Ensure a subdag containing a certificate below the GC round is rejected.
+        assert!(ensure_rounds_within_gc(&rounds, 3 + MAX_COMMIT_DELAY_IN_ROUNDS).is_err());
+
+        // Initialize the ledger service.
+        let (ledger_service, private_keys) = sample_advanced_ledger_service(1, rng);
+        let latest_block = ledger_service.latest_block();
+        let Authority::Quorum(latest_subdag) = latest_block.authority() else { unreachable!() };
+        let latest_round = latest_subdag.anchor_round();
+        // Ensure a candidate for the next block is fresh.
+        let block = sample_next_quorum_block(&ledger_service, &private_keys, rng);
+        let Authority::Quorum(subdag) = block.authority() else { unreachable!() };
+        assert!(ledger_service.check_subdag_freshness(subdag).is_ok());
+
+        // Certify the latest anchor round with a committee member other than the leader, which was not committed.
+        let leader = latest_subdag.leader_certificate().author();
+        let author =
+            private_keys.iter().find(|private_key| Address::try_from(*private_key).unwrap() != leader).unwrap();
+        let previous_certificate_ids = latest_subdag[&(latest_round - 1)].iter().map(BatchCertificate::id).collect();
+        let late_certificate = sample_signed_certificate(
+            &private_keys,
+            author,
+            latest_round,
+            latest_block.timestamp(),
+            IndexSet::new(),
+            previous_certificate_ids,
+            rng,
+        );
+        // Ensure a subdag with the late certificate, at the anchor round of the latest block, is fresh.
+        let mut rounds = (**subdag).clone();
+        rounds.insert(latest_round, [late_certificate].into_iter().collect());
+        let late_subdag = Subdag::from(rounds, Default::default()).unwrap();
+        assert!(ledger_service.check_subdag_freshness(&late_subdag).is_ok());
+
+        // Ensure a subdag that re-commits the anchor certificate of the latest block is rejected.
+        let mut rounds = (**subdag).clone();
+        rounds.insert(latest_round, [latest_subdag.leader_certificate().clone()].into_iter().collect());
+        let stale_subdag = Subdag::from(rounds, Default::default()).unwrap();
+        assert!(ledger_service.check_subdag_freshness(&stale_subdag).is_err());
+    }
+
+    #[test]
+    fn test_address_first_seen() {
+        let rng = &mut TestRng::default();
+
+        // Ensure an address introduced mid-chain is first seen at its block.
+        assert_eq!(first_height_where(0..10, |height| Ok(height >= 5)).unwrap(), Some(5));
+        // Ensure an address that was never seen is reported as such.
+        assert_eq!(first_height_where(0..10, |_| Ok(false)).unwrap(), None);
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure the genesis validators are first seen in the genesis block.
+        for address in ledger_service.current_committee().unwrap().members().keys() {
+            assert_eq!(ledger_service.address_first_seen(address).unwrap(), Some(0));
+        }
+        // Ensure an address without activity was never seen.
+        let outsider = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert_eq!(ledger_service.address_first_seen(&outsider).unwrap(), None);
+
+        // Ensure the scan is skipped if the node is shutting down.
+        ledger_service.shutdown.store(true, Ordering::Relaxed);
+        assert!(ledger_service.address_first_seen(&outsider).is_err());
+    }
+
+    #[test]
+    fn test_check_block_provers_authorized() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Sample a solution.
+        let solution = sample_solution(&ledger_service, rng);
+        let outsider = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+
+        // Ensure an authorized prover is accepted.
+        assert!(ensure_authorized_provers([&solution], &HashSet::from([solution.address()])).is_ok());
+        // Ensure an unauthorized prover is rejected.
+        assert!(ensure_authorized_provers([&solution], &HashSet::from([outsider])).is_err());
+        // Ensure an empty registry is permissionless.
+        assert!(ensure_authorized_provers([&solution], &HashSet::new()).is_ok());
+
+        // Ensure the genesis block is accepted.
+        let registry = HashSet::from([outsider]);
+        assert!(ledger_service.check_block_provers_authorized(&ledger_service.latest_block(), &registry).is_ok());
+    }
+
+    #[test]
+    fn test_current_committee_activation_round() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee that changed at round 6, and was carried over to rounds 8 and 10.
+        let previous = sample_committee(4, &[MIN_VALIDATOR_STAKE; 4], rng);
+        let changed = sample_committee(6, &[MIN_VALIDATOR_STAKE; 4], rng);
+        let current = Committee::new(10, changed.members().clone()).unwrap();
+        let history = [current.clone(), Committee::new(8, changed.members().clone()).unwrap(), changed, previous];
+        // Ensure the activation round is the round of the change.
+        assert_eq!(committee_activation_round(&current, history.map(Ok)).unwrap(), 6);
+        // Ensure a committee without history is active from its starting round.
+        assert_eq!(committee_activation_round(&current, []).unwrap(), 10);
+
+        // Ensure the genesis committee is active from the genesis round.
+        let ledger_service = sample_ledger_service();
+        assert_eq!(ledger_service.current_committee_activation_round().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_check_cumulative_target_monotonic() {
+        // Ensure a non-decreasing cumulative proof target is accepted.
+        assert!(ensure_monotonic_target(100, 100, false).is_ok());
+        assert!(ensure_monotonic_target(100, 150, true).is_ok());
+        // Ensure a reset upon reaching the coinbase target is accepted.
+        assert!(ensure_monotonic_target(100, 0, true).is_ok());
+        // Ensure a regressed cumulative proof target is rejected.
+        assert!(ensure_monotonic_target(100, 50, true).is_err());
+        assert!(ensure_monotonic_target(100, 0, false).is_err());
+
+        // Ensure the genesis block is accepted.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.check_cumulative_target_monotonic(&ledger_service.latest_block()).is_ok());
+    }
+
+    #[test]
+    fn test_get_rejected_transactions() {
+        let rng = &mut TestRng::default();
+
+        // Sample an execution, and reject it in favor of its fee.
+        let transaction =
+            snarkvm::ledger::block::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let Transaction::Execute(_, execution, Some(fee)) = transaction.clone() else {
+            panic!("Expected an execution transaction with a fee");
+        };
+        let rejected = ConfirmedTransaction::rejected_execute(
+            0,
+            Transaction::from_fee(fee).unwrap(),
+            snarkvm::ledger::block::Rejected::new_execution(execution),
+            vec![],
+        )
+        .unwrap();
+
+        // Ensure the rejected transaction is reported under its original ID.
+        let reported = rejected_transactions([&rejected]).unwrap();
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].0, transaction.id());
+
+        // Ensure the genesis block has no rejected transactions.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.get_rejected_transactions(0).unwrap().is_empty());
+        // Ensure a missing block fails.
+        assert!(ledger_service.get_rejected_transactions(1).is_err());
+    }
+
+    #[test]
+    fn test_weighted_certificate_latency() {
+        // Ensure the latency reflects a round with staggered timestamps,
+        // where the slowest validator holds half of the stake.
+        let latency = weighted_average_offset([(100, 1), (102, 1), (104, 2)]).unwrap();
+        assert_eq!(latency, (0.0 + 2.0 + 2.0 * 4.0) / 4.0);
+        // Ensure a round where all validators respond at once has no latency.
+        assert_eq!(weighted_average_offset([(100, 1), (100, 3)]), Some(0.0));
+        // Ensure a round without stake is handled.
+        assert_eq!(weighted_average_offset([(100, 0)]), None);
+        assert_eq!(weighted_average_offset([]), None);
+
+        // Ensure a round without committed certificates is rejected.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.weighted_certificate_latency(ledger_service.latest_round() + 2).is_err());
+    }
+
+    #[test]
+    fn test_verify_leader_reward() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        let round = ledger_service.latest_round() + 2;
+        // Compute the leader for the round.
+        let committee = ledger_service.get_previous_committee_for_round(round).unwrap();
+        let leader = committee.get_leader(round).unwrap();
+
+        // Ensure a reward attributed to a member other than the elected leader is rejected.
+        for address in committee.members().keys().filter(|address| **address != leader) {
+            assert!(ledger_service.check_leader_eligibility(round, address).is_err());
+        }
+        // Ensure the genesis block, which has no leader, is rejected.
+        assert!(ledger_service.verify_leader_reward(0).is_err());
+        // Ensure a missing block fails.
+        assert!(ledger_service.verify_leader_reward(1).is_err());
+
+        // Ensure the credited reward must match the expected reward, up to the rounding of each staker.
+        assert!(ensure_leader_reward(100, 100, 4).is_ok());
+        assert!(ensure_leader_reward(100, 96, 4).is_ok());
+        assert!(ensure_leader_reward(100, 95, 4).is_err());
+        assert!(ensure_leader_reward(100, 101, 4).is_err());
+        assert!(ensure_leader_reward(100, 0, 0).is_err());
+
+        // Ensure the leader rewards of committed blocks are credited to their leaders.
+        let rng = &mut TestRng::default();
+        let (ledger_service, private_keys) = sample_advanced_ledger_service(2, rng);
+        for height in 1..=ledger_service.latest_block_height() {
+            assert!(ledger_service.verify_leader_reward(height).is_ok());
+        }
+
+        // Commit a block in which the leader unbonds part of its stake.
+        let round = next_anchor_round(ledger_service.latest_round());
+        let leader = ledger_service.get_previous_committee_for_round(round).unwrap().get_leader(round).unwrap();
+        let leader =
+            private_keys.iter().find(|private_key| Address::try_from(*private_key).unwrap() == leader).unwrap();
+        let amount = format!("{}u64", MIN_VALIDATOR_STAKE / 4);
+        let unbond = sample_credits_execution(&ledger_service, leader, "unbond_public", &[&amount], rng);
+        let block = sample_next_quorum_block_with_transactions(&ledger_service, &private_keys, vec![unbond], rng);
+        ledger_service.ledger.advance_to_next_block(&block).unwrap();
+        // Ensure the unbond is not mistaken for a missing leader reward.
+        let unbond_height = ledger_service.latest_block_height();
+        assert!(ledger_service.verify_leader_reward(unbond_height).is_ok());
+
+        // Commit a block in which a delegator bonds to the first committee member, adding a staker.
+        let num_stakers = ledger_service.bonded_addresses().unwrap().len() as u64;
+        let delegator_private_key = PrivateKey::new(rng).unwrap();
+        let delegator = Address::try_from(&delegator_private_key).unwrap();
+        let validator = Address::try_from(&private_keys[0]).unwrap();
+        let amount = format!("{MIN_DELEGATOR_STAKE}u64");
+        let transfer = sample_credits_execution(
+            &ledger_service,
+            &private_keys[0],
+            "transfer_public",
+            &[&delegator.to_string(), &amount],
+            rng,
+        );
+        let block = sample_next_quorum_block_with_transactions(&ledger_service, &private_keys, vec![transfer], rng);
+        ledger_service.ledger.advance_to_next_block(&block).unwrap();
+        let bond = sample_credits_execution(
+            &ledger_service,
+            &delegator_private_key,
+            "bond_public",
+            &[&validator.to_string(), &amount],
+            rng,
+        );
+        let block = sample_next_quorum_block_with_transactions(&ledger_service, &private_keys, vec![bond], rng);
+        ledger_service.ledger.advance_to_next_block(&block).unwrap();
+        // Ensure the number of stakers is counted as of each block.
+        let latest_height = ledger_service.latest_block_height();
+        assert_eq!(ledger_service.num_stakers_at_height(latest_height).unwrap(), num_stakers + 1);
+        assert_eq!(ledger_service.num_stakers_at_height(latest_height - 1).unwrap(), num_stakers);
+        assert_eq!(ledger_service.num_stakers_at_height(unbond_height).unwrap(), num_stakers);
+        assert!(ledger_service.num_stakers_at_height(latest_height + 1).is_err());
+        // Ensure the leader rewards of the earlier blocks are still verified.
+        for height in 1..=latest_height {
+            assert!(ledger_service.verify_leader_reward(height).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_check_solution_count_cap() {
+        // Ensure a block at the cap is accepted.
+        assert!(ensure_solution_count(0, CurrentNetwork::MAX_SOLUTIONS).is_ok());
+        assert!(ensure_solution_count(CurrentNetwork::MAX_SOLUTIONS, CurrentNetwork::MAX_SOLUTIONS).is_ok());
+        // Ensure a block exceeding the cap is rejected.
+        assert!(ensure_solution_count(CurrentNetwork::MAX_SOLUTIONS + 1, CurrentNetwork::MAX_SOLUTIONS).is_err());
+
+        // Ensure the genesis block is accepted.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.check_solution_count_cap(&ledger_service.latest_block()).is_ok());
+    }
+
+    #[test]
+    fn test_address_bonding_events() {
+        let rng = &mut TestRng::default();
+
+        // Sample a validator.
+        let validator = Address::<CurrentNetwork>::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let credits = ProgramID::from_str("credits.aleo").unwrap();
+        let sample_future = |function_name: &str, arguments: &str| {
+            let arguments = arguments
+                .split(", ")
+                .map(|argument| Argument::Plaintext(Plaintext::from_str(argument).unwrap()))
+                .collect::<Vec<_>>();
+            Future::new(credits, Identifier::from_str(function_name).unwrap(), arguments)
+        };
+
+        // Ensure a bond followed by an unbond is decoded, along with their amounts.
+        let bond = sample_future("bond_public", &format!("{validator}, {validator}, {MIN_VALIDATOR_STAKE}u64"));
+        assert_eq!(bonding_event_of(&bond), Some((BondingEventKind::Bond, Some(MIN_VALIDATOR_STAKE))));
+        let unbond = sample_future("unbond_public", &format!("{validator}, 100u64"));
+        assert_eq!(bonding_event_of(&unbond), Some((BondingEventKind::Unbond, Some(100))));
+        let claim = sample_future("claim_unbond_public", &validator.to_string());
+        assert_eq!(bonding_event_of(&claim), Some((BondingEventKind::ClaimUnbond, None)));
+        // Ensure other functions are not bonding events.
+        let transfer = sample_future("transfer_public", &format!("{validator}, {validator}, 100u64"));
+        assert_eq!(bonding_event_of(&transfer), None);
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure an address without activity has no bonding events.
+        assert!(ledger_service.address_bonding_events(&validator, 0..1).unwrap().is_empty());
+        // Ensure an unbounded range is rejected.
+        assert!(ledger_service.address_bonding_events(&validator, 0..MAX_BLOCK_RANGE + 1).is_err());
+
+        // Ensure the scan is skipped if the node is shutting down.
+        ledger_service.shutdown.store(true, Ordering::Relaxed);
+        assert!(ledger_service.address_bonding_events(&validator, 0..1).is_err());
+    }
+
+    #[test]
+    fn test_check_no_double_spend() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transaction.
+        let transaction =
+            snarkvm::ledger::block::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let serial_number = *transaction.serial_numbers().next().unwrap();
+
+        // Ensure a transaction spending unspent serial numbers is accepted.
+        assert!(ensure_unspent_serial_numbers([&transaction], |_| Ok(false)).is_ok());
+        // Ensure a transaction reusing a spent serial number is rejected.
+        assert!(ensure_unspent_serial_numbers([&transaction], |spent| Ok(*spent == serial_number)).is_err());
+
+        // Ensure the check uses the spent serial numbers of the ledger.
+        let ledger_service = sample_ledger_service();
+        assert!(!ledger_service.ledger.contains_serial_number(&serial_number).unwrap());
+    }
+
+    #[test]
+    fn test_expected_next_round() {
+        // Ensure the next anchor round follows a cadence of two rounds.
+        assert_eq!(next_anchor_round(0), 2);
+        assert_eq!(next_anchor_round(2), 4);
+        assert_eq!(next_anchor_round(7), 8);
+
+        // Ensure the next block after genesis is expected at the first anchor round.
+        let ledger_service = sample_ledger_service();
+        assert_eq!(ledger_service.expected_next_round().unwrap(), next_anchor_round(ledger_service.latest_round()));
+    }
+
+    #[test]
+    fn test_get_validator_commission() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a configured commission is read from the committee state.
+        let committee_state =
+            Value::<CurrentNetwork>::from_str("{ microcredits: 100u64, is_open: true, commission: 5u8 }").unwrap();
+        let commission = struct_member(&committee_state, &Identifier::from_str("commission").unwrap()).unwrap();
+        assert_eq!(value_to_commission(&commission).unwrap(), 5);
+        // Ensure a malformed commission is rejected.
+        assert!(value_to_commission(&Value::<CurrentNetwork>::from_str("5u64").unwrap()).is_err());
+
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+        // Ensure the commission of each validator is unset, as the network does not support commissions.
+        for address in ledger_service.current_committee().unwrap().members().keys() {
+            assert_eq!(ledger_service.get_validator_commission(address).unwrap(), None);
+        }
+        // Ensure a non-member has no commission.
+        let outsider = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert_eq!(ledger_service.get_validator_commission(&outsider).unwrap(), None);
+    }
+
+    #[test]
+    fn test_check_timestamp_increasing() {
+        // Ensure a later timestamp is accepted.
+        assert!(ensure_timestamp_increasing(100, 101).is_ok());
+        // Ensure an equal or earlier timestamp is rejected.
+        assert!(ensure_timestamp_increasing(100, 100).is_err());
+        assert!(ensure_timestamp_increasing(100, 99).is_err());
+
+        // Ensure a block with the same timestamp as the latest block is rejected.
+        let ledger_service = sample_ledger_service();
+        assert!(ledger_service.check_timestamp_increasing(&ledger_service.latest_block()).is_err());
+    }
+
+    #[test]
+    fn test_bonded_addresses() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure the genesis validators are the bonded addresses.
+        let bonded_addresses = ledger_service.bonded_addresses().unwrap();
+        let committee = ledger_service.current_committee().unwrap();
+        assert_eq!(bonded_addresses.len(), committee.num_members());
+        assert!(bonded_addresses.iter().all(|address| committee.is_committee_member(*address)));
+
+        // Ensure the addresses are cached at the latest block.
+        let (hash, addresses) = ledger_service.bonded_addresses_cache.read().clone().unwrap();
+        assert_eq!(hash, ledger_service.latest_block().hash());
+        assert_eq!(addresses, bonded_addresses);
+
+        // Ensure the cached addresses are returned while the node is shutting down.
+        ledger_service.shutdown.store(true, Ordering::Relaxed);
+        assert_eq!(ledger_service.bonded_addresses().unwrap(), bonded_addresses);
+    }
+
+    #[test]
+    fn test_check_transition_count() {
+        let rng = &mut TestRng::default();
+        let max_transitions = Transaction::<CurrentNetwork>::MAX_TRANSITIONS;
+
+        // Ensure a transaction at the limit is accepted.
+        assert!(ensure_transition_count(1, max_transitions).is_ok());
+        assert!(ensure_transition_count(max_transitions, max_transitions).is_ok());
+        // Ensure an over-limit transaction is rejected.
+        assert!(ensure_transition_count(max_transitions + 1, max_transitions).is_err());
+
+        // Ensure a well-formed transaction is accepted.
+        let ledger_service = sample_ledger_service();
+        let transaction =
+            snarkvm::ledger::block::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        assert!(ledger_service.check_transition_count(&transaction).is_ok());
+    }
+
+    #[test]
+    fn test_epoch_solution_count() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure the solution count matches the genesis block.
+        let count = ledger_service.get_block_solution_count(0).unwrap();
+        assert_eq!(count, ledger_service.latest_block().solutions().map_or(0, |solutions| solutions.len()));
+        // Ensure the count of the first epoch, which only has the genesis block, matches the genesis block.
+        assert_eq!(ledger_service.epoch_solution_count(0).unwrap(), count as u64);
+        // Ensure an epoch that has not started is rejected.
+        assert!(ledger_service.epoch_solution_count(1).is_err());
+
+        // Ensure the count is skipped if the node is shutting down.
+        ledger_service.shutdown.store(true, Ordering::Relaxed);
+        assert!(ledger_service.epoch_solution_count(0).is_err());
+    }
+
+    #[test]
+    fn test_verify_committee_transition() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee.
+        let from = sample_committee(2, &[2 * MIN_VALIDATOR_STAKE; 4], rng);
+        let members = from.members().keys().copied().collect::<Vec<_>>();
+
+        // Ensure a legitimate transition, where staking rewards increased a stake, is accepted.
+        let mut rewarded = from.members().clone();
+        rewarded.insert(members[0], (2 * MIN_VALIDATOR_STAKE + 100, false));
+        let to = Committee::new(4, rewarded).unwrap();
+        assert!(ensure_committee_changes_explained(&from, &to, &HashSet::new()).is_ok());
+
+        // Ensure a legitimate transition, where a validator joined by bonding, is accepted.
+        let newcomer = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let mut joined = from.members().clone();
+        joined.insert(newcomer, (MIN_VALIDATOR_STAKE, false));
+        let to = Committee::new(4, joined).unwrap();
+        assert!(ensure_committee_changes_explained(&from, &to, &HashSet::from([newcomer])).is_ok());
+        // Ensure an illegitimate transition, where the validator joined without bonding, is rejected.
+        assert!(ensure_committee_changes_explained(&from, &to, &HashSet::new()).is_err());
+
+        // Ensure an illegitimate transition, where a stake decreased without unbonding, is rejected.
+        let mut slashed = from.members().clone();
+        slashed.insert(members[1], (MIN_VALIDATOR_STAKE, false));
+        let to = Committee::new(4, slashed).unwrap();
+        assert!(ensure_committee_changes_explained(&from, &to, &HashSet::new()).is_err());
+
+        // Ensure a validator whose committee entry was updated by a delegator's unbond is resolved,
+        // even though the unbond does not name the validator.
+        let credits = ProgramID::from_str("credits.aleo").unwrap();
+        let committee = Identifier::from_str("committee").unwrap();
+        let key = Plaintext::from(Literal::Address(members[2]));
+        let operations = [
+            FinalizeOperation::UpdateKeyValue(
+                to_mapping_id(&credits, &committee).unwrap(),
+                2,
+                to_key_id(&credits, &committee, &key).unwrap(),
+                Field::rand(rng),
+            ),
+            FinalizeOperation::UpdateKeyValue(Field::rand(rng), 0, Field::rand(rng), Field::rand(rng)),
+        ];
+        let candidates = members.iter().copied().collect();
+        assert_eq!(updated_committee_members(&operations, &candidates).unwrap(), HashSet::from([members[2]]));
+
+        // Ensure the genesis committee has no transition.
+        let ledger_service = sample_ledger_service();
+        let round = ledger_service.latest_round();
+        assert!(ledger_service.verify_committee_transition(round, round).is_ok());
+        assert!(ledger_service.verify_committee_transition(round + 1, round).is_err());
+    }
+
+    #[test]
+    fn test_get_program_deployment_height() {
+        // Initialize the ledger service.
+        let ledger_service = sample_ledger_service();
+
+        // Ensure the built-in program is reported at the genesis height.
+        let credits = ProgramID::from_str("credits.aleo").unwrap();
+        assert_eq!(ledger_service.get_program_deployment_height(&credits).unwrap(), Some(0));
+        // Ensure an undeployed program is reported as such.
+        let program_id = ProgramID::from_str("new_program.aleo").unwrap();
+        assert_eq!(ledger_service.get_program_deployment_height(&program_id).unwrap(), None);
+    }
+
+    #[test]
+    fn test_verify_subdag_committees() {
+        let rng = &mut TestRng::default();
+
+        // Sample an anchor certificate with previous certificates.
+        let (anchor, previous_certificates) =
+            snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate_with_previous_certificates(
+                3, rng,
+            );
+        let rounds = BTreeMap::from([
+            (2, previous_certificates.iter().cloned().collect::<IndexSet<_>>()),
+            (3, IndexSet::from([anchor.clone()])),
+        ]);
+        // Initialize a committee of the author and signers of the certificates in each round,
+        // such that the committee changes from round 2 to round 3.
+        let signers_of = |certificates: &[BatchCertificate<CurrentNetwork>]| {
+            certificates
+                .iter()
+                .flat_map(|certificate| {
+                    std::iter::once(certificate.author())
+                        .chain(certificate.signatures().map(|signature| signature.to_address()))
+                })
+                .collect::<IndexSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+        let committee_2 = snarkvm::ledger::committee::test_helpers::sample_committee_for_round_and_members(
+            2,
+            signers_of(&previous_certificates),
+            rng,
+        );
+        let committee_3 = snarkvm::ledger::committee::test_helpers::sample_committee_for_round_and_members(
+            3,
+            signers_of(&[anchor]),
+            rng,
+        );
+
+        // Ensure the certificates are accepted against the committee for their round.
+        let committee_for_round = |round| match round {
+            2 => Ok(committee_2.clone()),
+            _ => Ok(committee_3.clone()),
+        };
+        assert!(ensure_subdag_quorums(&rounds, committee_for_round).is_ok());
+        // Ensure the certificates are rejected against the committee for another round.
+        let swapped_committee_for_round = |round| match round {
+            2 => Ok(committee_3.clone()),
+            _ => Ok(committee_2.clone()),
+        };
+        assert!(ensure_subdag_quorums(&rounds, swapped_committee_for_round).is_err());
+        // Ensure a missing committee is rejected.
+        assert!(ensure_subdag_quorums(&rounds, |round| bail!("No committee for round {round}")).is_err());
+    }
 }
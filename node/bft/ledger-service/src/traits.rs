@@ -15,7 +15,7 @@
 use snarkvm::{
     ledger::{
         block::{Block, Transaction},
-        coinbase::{ProverSolution, PuzzleCommitment},
+        coinbase::{CoinbaseVerifyingKey, EpochChallenge, ProverSolution, PuzzleCommitment},
         committee::Committee,
         narwhal::{BatchCertificate, Data, Subdag, Transmission, TransmissionID},
     },
@@ -23,7 +23,7 @@ use snarkvm::{
 };
 
 use indexmap::IndexMap;
-use std::{fmt::Debug, ops::Range};
+use std::{fmt::Debug, ops::Range, time::Duration};
 
 #[async_trait]
 pub trait LedgerService<N: Network>: Debug + Send + Sync {
@@ -39,6 +39,32 @@ pub trait LedgerService<N: Network>: Debug + Send + Sync {
     /// Returns `true` if the given block height exists in the ledger.
     fn contains_block_height(&self, height: u32) -> bool;
 
+    /// Returns `true` if the ledger has reached the given target block height.
+    fn is_synced_to(&self, target_height: u32) -> bool {
+        self.latest_block_height() >= target_height
+    }
+
+    /// Returns the sync progress towards the given target block height, as a ratio from 0.0 to 1.0.
+    fn sync_progress(&self, target_height: u32) -> f64 {
+        match target_height {
+            0 => 1.0,
+            _ => (self.latest_block_height() as f64 / target_height as f64).min(1.0),
+        }
+    }
+
+    /// Returns the estimated time to sync to the given target block height at the given rate (in blocks per second),
+    /// or `None` if the ledger has already reached the target height or the rate is not positive.
+    fn estimate_sync_eta(&self, target_height: u32, blocks_per_sec: f64) -> Option<Duration> {
+        // Ensure the ledger is behind the target height, and the rate is positive.
+        if self.is_synced_to(target_height) || blocks_per_sec.is_nan() || blocks_per_sec <= 0.0 {
+            return None;
+        }
+        // Compute the number of remaining blocks.
+        let remaining_blocks = target_height.saturating_sub(self.latest_block_height());
+        // Return the estimated time to sync the remaining blocks.
+        Duration::try_from_secs_f64(remaining_blocks as f64 / blocks_per_sec).ok()
+    }
+
     /// Returns the block height for the given block hash, if it exists.
     fn get_block_height(&self, hash: &N::BlockHash) -> Result<u32>;
 
@@ -114,3 +140,26 @@ pub trait LedgerService<N: Network>: Debug + Send + Sync {
     #[cfg(feature = "ledger-write")]
     fn advance_to_next_block(&self, block: &Block<N>) -> Result<()>;
 }
+
+/// A verifier of prover solutions, used by the ledger service to check unconfirmed solutions.
+pub trait SolutionVerifier<N: Network>: Send + Sync {
+    /// Returns `true` if the given solution is valid for the given epoch challenge and proof target.
+    fn verify_solution(
+        &self,
+        solution: &ProverSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<bool>;
+}
+
+impl<N: Network> SolutionVerifier<N> for CoinbaseVerifyingKey<N> {
+    /// Returns `true` if the given solution is valid for the given epoch challenge and proof target.
+    fn verify_solution(
+        &self,
+        solution: &ProverSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<bool> {
+        solution.verify(self, epoch_challenge, proof_target)
+    }
+}
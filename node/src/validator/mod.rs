@@ -107,6 +107,8 @@ impl<N: Network, C: ConsensusStorage<N>> Validator<N, C> {
 
         // Initialize the ledger service.
         let ledger_service = Arc::new(CoreLedgerService::new(ledger.clone(), shutdown.clone()));
+        // Ensure the genesis block is well-formed.
+        ledger_service.verify_genesis_block()?;
         // Initialize the sync module.
         let sync = BlockSync::new(BlockSyncMode::Gateway, ledger_service.clone());
 